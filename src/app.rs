@@ -2,11 +2,22 @@ use libadwaita::prelude::*;
 use libadwaita::Application;
 use gtk4::{gio, CssProvider};
 use std::cell::RefCell;
+use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
+use crate::widgets::layout_preview;
 use crate::window::FuseWindow;
-use crate::core::config::ColorConfig;
+use crate::core::backgrounds;
+use crate::core::color;
+use crate::core::config::{clamp_scale, ColorConfig, DEFAULT_CORNER_RADIUS};
+use crate::core::exporters;
+use crate::core::gtk_settings;
+use crate::core::quickshell;
+use crate::core::slideshow;
+use crate::core::templates;
+use crate::core::theme_schedule;
 
 const APP_ID: &str = "com.alloy.fuse";
 
@@ -14,6 +25,7 @@ pub struct FuseApp {
     app: Application,
     _config: Arc<Mutex<ColorConfig>>,
     _css_provider: Rc<RefCell<Option<CssProvider>>>,
+    _user_css_provider: Rc<RefCell<Option<CssProvider>>>,
     _monitors: Vec<gio::FileMonitor>,
 }
 
@@ -24,11 +36,21 @@ impl FuseApp {
             .application_id(APP_ID)
             .build();
         let css_provider = Rc::new(RefCell::new(None));
+        let user_css_provider = Rc::new(RefCell::new(None));
 
         let css_provider_clone = css_provider.clone();
+        let user_css_provider_clone = user_css_provider.clone();
         let config_startup = Arc::clone(&config);
         app.connect_startup(move |_| {
+            if ColorConfig::migrate_corner_radius() {
+                *config_startup.lock().unwrap() = ColorConfig::load();
+            }
             load_css_with_colors(&css_provider_clone, &config_startup);
+            load_user_css(&user_css_provider_clone);
+            start_theme_schedule_timer(Arc::clone(&config_startup));
+            if config_startup.lock().unwrap().slideshow_enabled.unwrap_or(false) {
+                let _ = slideshow::spawn_daemon();
+            }
         });
 
         let config_activate = Arc::clone(&config);
@@ -40,12 +62,13 @@ impl FuseApp {
         // Start monitoring for color changes
         let css_provider_monitor = css_provider.clone();
         let config_monitor = Arc::clone(&config);
-        let monitors = start_color_monitoring(css_provider_monitor, config_monitor);
+        let monitors = start_color_monitoring(css_provider_monitor, user_css_provider.clone(), config_monitor);
 
         Self { 
             app,
             _config: config,
             _css_provider: css_provider,
+            _user_css_provider: user_css_provider,
             _monitors: monitors,
         }
     }
@@ -82,23 +105,62 @@ fn load_css_with_colors(css_provider_rc: &Rc<RefCell<Option<CssProvider>>>, conf
         config.text,
         config.accent,
         config.accent,
-        get_contrasting_text_color(&config.accent), // calculated accent fg
+        color::get_contrasting_text_color(&config.accent), // calculated accent fg
         config.secondary,
         config.background // view matches window
     );
 
+    // Tonal accent scale: @accent_50 … @accent_900, @accent_hover_color, @accent_pressed_color
+    for (step, _) in color::ACCENT_TONES {
+        dynamic_css.push_str(&format!("@define-color accent_{} {};\n", step, config.accent_tone(step)));
+    }
+    dynamic_css.push_str(&format!(
+        "@define-color accent_hover_color {};\n@define-color accent_pressed_color {};\n\n",
+        config.accent_tone("hover"),
+        config.accent_tone("pressed")
+    ));
+
+    // Semantic status colors
+    for role in ["error", "warning", "success", "info"] {
+        let value = config.status_color(role);
+        dynamic_css.push_str(&format!(
+            "@define-color {role}_color {value};\n@define-color {role}_bg_color {value};\n@define-color {role}_fg_color {fg};\n",
+            role = role,
+            value = value,
+            fg = color::get_contrasting_text_color(&value),
+        ));
+    }
+    dynamic_css.push('\n');
+
+    // Corner radius tiers, scaled from the single radius setting
+    let radii = radius_tiers(config.corner_radius());
+    let supports_css_vars = gtk4::major_version() > 4 || gtk4::minor_version() >= 16;
+    if supports_css_vars {
+        dynamic_css.push_str(":root {\n");
+        for (name, px) in &radii {
+            dynamic_css.push_str(&format!("    --radius-{}: {}px;\n", name, px));
+        }
+        dynamic_css.push_str("}\n\n");
+    }
+
     // Append base CSS
     dynamic_css.push_str(base_css);
-    
-    // Apply rounding setting (simple replace to avoid regex on large CSS at startup)
-    let rounding = config.rounding.as_deref().unwrap_or("rounded");
-    if rounding == "sharp" {
-        dynamic_css = dynamic_css.replace("border-radius: 8px", "border-radius: 0px")
-            .replace("border-radius: 6px", "border-radius: 0px")
-            .replace("border-radius: 12px", "border-radius: 0px")
-            .replace("border-radius: 4px", "border-radius: 0px");
+
+    // Fonts go last so they win over the base stylesheet
+    dynamic_css.push_str(&font_css(&config));
+
+    if !supports_css_vars {
+        // GTK < 4.16 has no var() and no other way to name a length, so the generated
+        // var(--radius-*) references are resolved here as text. Only those tokens are touched;
+        // the user stylesheet (fuse.css) is loaded separately and can't use them on these versions.
+        for (name, px) in &radii {
+            dynamic_css = dynamic_css.replace(&format!("var(--radius-{})", name), &format!("{}px", px));
+        }
     }
     
+    // Text scale isn't expressible in CSS - it goes through GtkSettings and applies live
+    apply_text_scale(config.text_scale.unwrap_or(100));
+
     let provider = CssProvider::new();
     provider.load_from_string(&dynamic_css);
     
@@ -120,19 +182,189 @@ fn load_css_with_colors(css_provider_rc: &Rc<RefCell<Option<CssProvider>>>, conf
     *css_provider_rc.borrow_mut() = Some(provider);
 }
 
-fn start_color_monitoring(css_provider_rc: Rc<RefCell<Option<CssProvider>>>, config: Arc<Mutex<ColorConfig>>) -> Vec<gio::FileMonitor> {
+thread_local! {
+    // Parse errors from the last fuse.css load, and the banners showing them
+    static USER_CSS_ERRORS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static USER_CSS_BANNERS: RefCell<Vec<gtk4::glib::WeakRef<libadwaita::Banner>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Optional user stylesheet, loaded on top of everything else.
+pub fn user_css_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".config").join("alloy").join("fuse.css"))
+}
+
+/// (Re)load ~/.config/alloy/fuse.css into its own provider, above the generated CSS.
+/// A missing file just removes the old provider.
+fn load_user_css(provider_rc: &Rc<RefCell<Option<CssProvider>>>) {
+    let display = gtk4::gdk::Display::default().expect("Could not connect to display");
+    if let Some(old_provider) = provider_rc.borrow_mut().take() {
+        gtk4::style_context_remove_provider_for_display(&display, &old_provider);
+    }
+    USER_CSS_ERRORS.with(|errors| errors.borrow_mut().clear());
+
+    let path = match user_css_path() {
+        Some(path) if path.is_file() => path,
+        _ => {
+            update_user_css_banners();
+            return;
+        }
+    };
+
+    let provider = CssProvider::new();
+    provider.connect_parsing_error(|_, section, error| {
+        let location = section.start_location();
+        let message = format!("line {}:{}: {}", location.lines() + 1, location.line_chars() + 1, error.message());
+        USER_CSS_ERRORS.with(|errors| errors.borrow_mut().push(message));
+    });
+    match std::fs::read_to_string(&path) {
+        Ok(css) => provider.load_from_string(&css),
+        Err(e) => USER_CSS_ERRORS.with(|errors| errors.borrow_mut().push(e.to_string())),
+    }
+
+    gtk4::style_context_add_provider_for_display(
+        &display,
+        &provider,
+        gtk4::STYLE_PROVIDER_PRIORITY_USER,
+    );
+    *provider_rc.borrow_mut() = Some(provider);
+    update_user_css_banners();
+}
+
+/// Banner that shows up whenever fuse.css has parse errors; hides again once it's fixed.
+pub fn create_user_css_banner() -> libadwaita::Banner {
+    let banner = libadwaita::Banner::new("");
+    banner.set_use_markup(false);
+    banner.set_button_label(Some("Dismiss"));
+    banner.connect_button_clicked(|b| b.set_revealed(false));
+
+    USER_CSS_BANNERS.with(|banners| {
+        let mut banners = banners.borrow_mut();
+        banners.retain(|weak| weak.upgrade().is_some());
+        banners.push(banner.downgrade());
+    });
+    update_user_css_banners();
+    banner
+}
+
+fn update_user_css_banners() {
+    let title = USER_CSS_ERRORS.with(|errors| {
+        let errors = errors.borrow();
+        match errors.len() {
+            0 => None,
+            1 => Some(format!("fuse.css: {}", errors[0])),
+            n => Some(format!("fuse.css: {} (and {} more)", errors[0], n - 1)),
+        }
+    });
+    USER_CSS_BANNERS.with(|banners| {
+        for banner in banners.borrow().iter().filter_map(|weak| weak.upgrade()) {
+            if let Some(ref title) = title {
+                banner.set_title(title);
+            }
+            banner.set_revealed(title.is_some());
+        }
+    });
+}
+
+thread_local! {
+    // Xft DPI before we touched it, so text scale is relative to the desktop's own setting
+    static BASE_XFT_DPI: std::cell::Cell<Option<i32>> = const { std::cell::Cell::new(None) };
+}
+
+fn apply_text_scale(percent: u8) {
+    let Some(settings) = gtk4::Settings::default() else {
+        return;
+    };
+    let base = BASE_XFT_DPI.with(|base| match base.get() {
+        Some(dpi) => dpi,
+        None => {
+            let dpi = settings.gtk_xft_dpi();
+            let dpi = if dpi > 0 { dpi } else { 96 * 1024 };
+            base.set(Some(dpi));
+            dpi
+        }
+    });
+    let dpi = (base as f64 * clamp_scale(percent) as f64 / 100.0).round() as i32;
+    if settings.gtk_xft_dpi() != dpi {
+        settings.set_gtk_xft_dpi(dpi);
+    }
+}
+
+/// CSS for the configured interface / monospace fonts (empty when both are system defaults).
+fn font_css(config: &ColorConfig) -> String {
+    let quote = |family: &str| format!("\"{}\"", family.replace(['\\', '"'], ""));
+    let mut css = String::new();
+
+    let mut ui_rules = Vec::new();
+    if let Some(ref family) = config.ui_font {
+        ui_rules.push(format!("font-family: {};", quote(family)));
+    }
+    if let Some(size) = config.ui_font_size {
+        ui_rules.push(format!("font-size: {}pt;", size));
+    }
+    if !ui_rules.is_empty() {
+        css.push_str(&format!("\nwindow {{ {} }}\n", ui_rules.join(" ")));
+    }
+
+    let mut mono_rules = Vec::new();
+    if let Some(ref family) = config.mono_font {
+        mono_rules.push(format!("font-family: {}, monospace;", quote(family)));
+    }
+    if let Some(size) = config.mono_font_size {
+        mono_rules.push(format!("font-size: {}pt;", size));
+    }
+    if !mono_rules.is_empty() {
+        css.push_str(&format!(".monospace, textview.monospace {{ {} }}\n", mono_rules.join(" ")));
+    }
+
+    css
+}
+
+/// Radius tiers used by style.css and their size (px) at the default radius.
+const RADIUS_TIERS: &[(&str, f64)] = &[
+    ("xxs", 2.0),
+    ("xs", 4.0),
+    ("sm", 6.0),
+    ("md", 8.0),
+    ("lg", 10.0),
+    ("xl", 12.0),
+    ("xxl", 14.0),
+    ("xxxl", 16.0),
+];
+
+/// Scale every tier proportionally so `radius` == DEFAULT_CORNER_RADIUS reproduces the stock look.
+fn radius_tiers(radius: u8) -> Vec<(&'static str, u32)> {
+    let factor = radius as f64 / DEFAULT_CORNER_RADIUS as f64;
+    RADIUS_TIERS
+        .iter()
+        .map(|(name, base)| (*name, (base * factor).round() as u32))
+        .collect()
+}
+
+fn start_color_monitoring(
+    css_provider_rc: Rc<RefCell<Option<CssProvider>>>,
+    user_css_provider_rc: Rc<RefCell<Option<CssProvider>>>,
+    config: Arc<Mutex<ColorConfig>>) -> Vec<gio::FileMonitor> {
     let mut monitors = Vec::new();
     let config_path = ColorConfig::get_config_path();
+
+    // Palette the exporters last ran for - colors.json also changes for non-color settings
+    let last_palette = Rc::new(RefCell::new(String::new()));
+    run_palette_hooks_if_changed(&config.lock().unwrap(), &last_palette);
+    refresh_lockscreen_background();
     
     // Monitor colors.json
     let file = gio::File::for_path(&config_path);
     if let Ok(monitor) = file.monitor_file(gio::FileMonitorFlags::NONE, gio::Cancellable::NONE) {
         let css_provider_rc_clone = css_provider_rc.clone();
         let config_clone = Arc::clone(&config);
+        let last_palette_clone = Rc::clone(&last_palette);
         monitor.connect_changed(move |_, _, _, event_type| {
             if matches!(event_type, gio::FileMonitorEvent::Changed | gio::FileMonitorEvent::ChangesDoneHint) {
                 *config_clone.lock().unwrap() = ColorConfig::load();
                 load_css_with_colors(&css_provider_rc_clone, &config_clone);
+                run_palette_hooks_if_changed(&config_clone.lock().unwrap(), &last_palette_clone);
+                layout_preview::preview_set(&config_clone.lock().unwrap());
+                refresh_lockscreen_background();
             }
         });
         monitors.push(monitor);
@@ -143,50 +375,89 @@ fn start_color_monitoring(css_provider_rc: Rc<RefCell<Option<CssProvider>>>, con
     if let Ok(monitor) = notification_file.monitor_file(gio::FileMonitorFlags::NONE, gio::Cancellable::NONE) {
         let css_provider_rc_clone = css_provider_rc.clone();
         let config_clone = Arc::clone(&config);
+        let last_palette_clone = Rc::clone(&last_palette);
         monitor.connect_changed(move |_, _, _, event_type| {
             if matches!(event_type, gio::FileMonitorEvent::Changed | gio::FileMonitorEvent::ChangesDoneHint | gio::FileMonitorEvent::Created) {
                 *config_clone.lock().unwrap() = ColorConfig::load();
                 load_css_with_colors(&css_provider_rc_clone, &config_clone);
+                run_palette_hooks_if_changed(&config_clone.lock().unwrap(), &last_palette_clone);
+                layout_preview::preview_set(&config_clone.lock().unwrap());
             }
         });
         monitors.push(monitor);
     }
+
+    // Monitor ~/.config/alloy/fuse.css (it may not exist yet)
+    if let Some(user_css_path) = user_css_path() {
+        let user_css_file = gio::File::for_path(&user_css_path);
+        if let Ok(monitor) = user_css_file.monitor_file(gio::FileMonitorFlags::NONE, gio::Cancellable::NONE) {
+            monitor.connect_changed(move |_, _, _, event_type| {
+                if matches!(event_type, gio::FileMonitorEvent::ChangesDoneHint | gio::FileMonitorEvent::Created | gio::FileMonitorEvent::Deleted) {
+                    load_user_css(&user_css_provider_rc);
+                }
+            });
+            monitors.push(monitor);
+        }
+    }
     
     monitors
 }
 
-fn get_contrasting_text_color(hex: &str) -> String {
-    let hex = hex.trim().trim_start_matches('#');
-    
-    // Explicit handle for common white/black (optimization and safety)
-    if hex.eq_ignore_ascii_case("ffffff") || hex.eq_ignore_ascii_case("fff") || hex.eq_ignore_ascii_case("white") {
-        return "#000000".to_string();
-    }
-    if hex.eq_ignore_ascii_case("000000") || hex.eq_ignore_ascii_case("000") || hex.eq_ignore_ascii_case("black") {
-        return "#ffffff".to_string();
-    }
-
-    // Handle 3-digit hex
-    if hex.len() == 3 {
-        let r_char = &hex[0..1];
-        let g_char = &hex[1..2];
-        let b_char = &hex[2..3];
-        let full_hex = format!("{}{}{}{}{}{}", r_char, r_char, g_char, g_char, b_char, b_char);
-        return get_contrasting_text_color(&full_hex);
-    }
-
-    if hex.len() == 6 {
-        if let (Ok(r), Ok(g), Ok(b)) = (
-            u8::from_str_radix(&hex[0..2], 16),
-            u8::from_str_radix(&hex[2..4], 16),
-            u8::from_str_radix(&hex[4..6], 16),
-        ) {
-            // Calculate brightness using standard formula
-            let brightness = (r as f32 * 0.299 + g as f32 * 0.587 + b as f32 * 0.114) / 255.0;
-            if brightness > 0.5 { // Lowered threshold slightly to 0.5 for better white detection
-                return "#000000".to_string(); // Bright background -> Dark text
-            }
+/// Re-render the lock screen background in the background after the wallpaper or the lock
+/// screen settings changed. Saving the new path changes colors.json again, which is a no-op here;
+/// settings saved mid-render are handled by update_lockscreen_background itself.
+fn refresh_lockscreen_background() {
+    static RENDERING: AtomicBool = AtomicBool::new(false);
+    if RENDERING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    gtk4::glib::MainContext::default().spawn_local(async {
+        let _ = gio::spawn_blocking(backgrounds::update_lockscreen_background).await;
+        RENDERING.store(false, Ordering::SeqCst);
+    });
+}
+
+/// Check the automatic light/dark schedule now and then once a minute.
+/// The color monitor picks up the saved colors.json and reloads the CSS.
+fn start_theme_schedule_timer(config: Arc<Mutex<ColorConfig>>) {
+    let check = move || {
+        let mut cfg = ColorConfig::load();
+        if theme_schedule::apply_schedule(&mut cfg) && cfg.save().is_ok() {
+            *config.lock().unwrap() = cfg;
+            let _ = quickshell::notify_color_change();
         }
+    };
+    check();
+    gtk4::glib::timeout_add_seconds_local(60, move || {
+        check();
+        gtk4::glib::ControlFlow::Continue
+    });
+}
+
+/// Re-export the palette, re-render user templates and publish the light/dark preference,
+/// but only when the colors actually changed.
+fn run_palette_hooks_if_changed(config: &ColorConfig, last_palette: &Rc<RefCell<String>>) {
+    let palette = format!(
+        "{} {} {} {} {} {} {} {} {}",
+        config.background,
+        config.primary,
+        config.secondary,
+        config.text,
+        config.accent,
+        config.status_color("error"),
+        config.status_color("warning"),
+        config.status_color("success"),
+        config.status_color("info")
+    );
+    if *last_palette.borrow() == palette {
+        return;
+    }
+    *last_palette.borrow_mut() = palette;
+    for (exporter, error) in exporters::export_enabled(config) {
+        eprintln!("Failed to export {} colors: {}", exporter.label(), error);
+    }
+    for (template, error) in templates::render_all(config) {
+        eprintln!("Failed to render template {}: {}", template, error);
     }
-    "#ffffff".to_string() // Default to white text
+    let _ = gtk_settings::publish_color_scheme(!color::is_light(&config.background));
 }
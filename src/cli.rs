@@ -0,0 +1,93 @@
+use crate::core::color;
+use crate::core::config::ColorConfig;
+use crate::core::gtk_settings;
+use crate::core::presets;
+use crate::core::quickshell;
+use crate::core::slideshow::{self, SlideshowCommand};
+use crate::core::theme_schedule;
+
+const USAGE: &str = "Usage:\n  \
+    fuse                          open the settings window\n  \
+    fuse --apply-theme-schedule   switch light/dark according to the automatic schedule\n  \
+    fuse --theme <light|dark>     switch the current preset to its light or dark variant\n  \
+    fuse --slideshow              run the wallpaper slideshow (started by Fuse when enabled)\n  \
+    fuse --slideshow-next         skip to the next wallpaper\n  \
+    fuse --slideshow-previous     go back to the previous wallpaper\n  \
+    fuse --slideshow-pause        pause the slideshow\n  \
+    fuse --slideshow-resume       resume the slideshow\n  \
+    fuse --slideshow-stop         stop the slideshow process";
+
+/// Handle command-line invocations that don't need a window.
+/// Returns Some(exit code) when the arguments were handled, None to start the GUI.
+pub fn run(args: &[String]) -> Option<i32> {
+    let command = args.first()?;
+    match command.as_str() {
+        "--apply-theme-schedule" => {
+            let mut cfg = ColorConfig::load();
+            if cfg.theme_mode.as_deref() != Some("auto") {
+                println!("Automatic theme switching is off");
+                return Some(0);
+            }
+            if !theme_schedule::apply_schedule(&mut cfg) {
+                println!("Already {}", presets::current_variant(&cfg));
+                return Some(0);
+            }
+            Some(save_and_notify(&cfg))
+        }
+        "--theme" => {
+            let variant = match args.get(1).map(|s| s.as_str()) {
+                Some(v @ ("light" | "dark")) => v,
+                _ => {
+                    eprintln!("{}", USAGE);
+                    return Some(2);
+                }
+            };
+            let mut cfg = ColorConfig::load();
+            if !presets::apply_any_variant(&mut cfg, variant) {
+                eprintln!("No preset has a {} variant", variant);
+                return Some(1);
+            }
+            Some(save_and_notify(&cfg))
+        }
+        "--slideshow" => {
+            if !ColorConfig::load().slideshow_enabled.unwrap_or(false) {
+                eprintln!("The slideshow is disabled - enable it in Appearance first");
+                return Some(1);
+            }
+            Some(slideshow::run_daemon())
+        }
+        "--slideshow-next" => Some(send_slideshow_command(SlideshowCommand::Next)),
+        "--slideshow-previous" => Some(send_slideshow_command(SlideshowCommand::Previous)),
+        "--slideshow-pause" => Some(send_slideshow_command(SlideshowCommand::Pause)),
+        "--slideshow-resume" => Some(send_slideshow_command(SlideshowCommand::Resume)),
+        "--slideshow-stop" => Some(send_slideshow_command(SlideshowCommand::Stop)),
+        "--help" | "-h" => {
+            println!("{}", USAGE);
+            Some(0)
+        }
+        // Anything else (e.g. GApplication options) goes to GTK
+        _ => None,
+    }
+}
+
+fn save_and_notify(cfg: &ColorConfig) -> i32 {
+    if let Err(e) = cfg.save() {
+        eprintln!("Failed to save colors: {}", e);
+        return 1;
+    }
+    let _ = quickshell::notify_color_change();
+    // No window running to react to the change - publish the preference ourselves
+    let _ = gtk_settings::publish_color_scheme(!color::is_light(&cfg.background));
+    println!("Switched to {}", presets::current_variant(cfg));
+    0
+}
+
+fn send_slideshow_command(command: SlideshowCommand) -> i32 {
+    match slideshow::send_command(command) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("Slideshow: {}", e);
+            1
+        }
+    }
+}
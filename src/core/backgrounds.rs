@@ -0,0 +1,205 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::color;
+use crate::core::config::ColorConfig;
+use crate::core::monitors;
+use crate::core::thumbnails;
+
+/// Size used when the compositor can't tell us the largest output
+const DEFAULT_SIZE: (u32, u32) = (3840, 2160);
+
+/// How a wallpaper is laid out on the output (config key, label).
+pub const FIT_MODES: &[(&str, &str)] = &[
+    ("fill", "Fill"),
+    ("fit", "Fit"),
+    ("center", "Center"),
+    ("tile", "Tile"),
+    ("stretch", "Stretch"),
+];
+
+pub const DEFAULT_FIT: &str = "fill";
+
+/// What the lock screen shows behind its widgets (config key, label).
+pub const LOCKSCREEN_MODES: &[(&str, &str)] = &[
+    ("wallpaper", "Wallpaper"),
+    ("blurred", "Blurred"),
+    ("dimmed", "Dimmed"),
+    ("image", "Image"),
+    ("color", "Color"),
+];
+
+pub const DEFAULT_LOCKSCREEN_MODE: &str = "wallpaper";
+
+/// Blurring happens at this width - plenty once blurred, and far faster than at 4K
+const BLUR_WIDTH: u32 = 960;
+const BLUR_SIGMA: f32 = 10.0;
+/// Brightness kept by the dimmed background
+const DIM_FACTOR: f32 = 0.5;
+
+/// Generated solid and gradient backgrounds live here.
+pub fn generated_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("/tmp"))
+        .join("alloy")
+        .join("backgrounds")
+}
+
+/// Largest connected output, so one image covers every screen.
+fn target_size() -> (u32, u32) {
+    monitors::list_monitors()
+        .iter()
+        .filter(|m| m.width > 0 && m.height > 0)
+        .map(|m| (m.width as u32, m.height as u32))
+        .max_by_key(|(w, h)| w * h)
+        .unwrap_or(DEFAULT_SIZE)
+}
+
+fn rgb(hex: &str) -> [u8; 3] {
+    let (r, g, b) = color::parse_hex(hex).unwrap_or((0, 0, 0));
+    [r, g, b]
+}
+
+/// A single-color background image. Returns its path.
+pub fn generate_solid(hex: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let fill = rgb(hex);
+    let path = generated_dir().join(format!("solid-{}.png", color::strip_hash(&color::to_hex(hex))));
+    let (width, height) = target_size();
+    let image = image::RgbImage::from_pixel(width, height, image::Rgb(fill));
+    fs::create_dir_all(generated_dir())?;
+    image.save(&path)?;
+    Ok(path)
+}
+
+/// A diagonal two-stop gradient (top-left `from`, bottom-right `to`). Returns its path.
+pub fn generate_gradient(from: &str, to: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let (start, end) = (rgb(from), rgb(to));
+    let path = generated_dir().join(format!(
+        "gradient-{}-{}.png",
+        color::strip_hash(&color::to_hex(from)),
+        color::strip_hash(&color::to_hex(to))
+    ));
+    let (width, height) = target_size();
+    let span = (width + height).saturating_sub(2).max(1) as f64;
+    let image = image::RgbImage::from_fn(width, height, |x, y| {
+        let t = (x + y) as f64 / span;
+        let channel = |i: usize| (start[i] as f64 + (end[i] as f64 - start[i] as f64) * t).round() as u8;
+        image::Rgb([channel(0), channel(1), channel(2)])
+    });
+    fs::create_dir_all(generated_dir())?;
+    image.save(&path)?;
+    Ok(path)
+}
+
+/// Image the lock screen should show for the current settings, rendering the blurred or
+/// dimmed copy of the wallpaper if it isn't cached yet. Slow on a miss - call off the main thread.
+pub fn lockscreen_background(config: &ColorConfig) -> Result<Option<PathBuf>, Box<dyn std::error::Error>> {
+    let wallpaper = config.last_wallpaper.as_deref().filter(|w| !w.is_empty()).map(PathBuf::from);
+    match config.lockscreen_background.as_deref().unwrap_or(DEFAULT_LOCKSCREEN_MODE) {
+        mode @ ("blurred" | "dimmed") => match wallpaper {
+            Some(source) => render_lockscreen(&source, mode).map(Some),
+            None => Ok(None),
+        },
+        "image" => Ok(config
+            .lockscreen_image
+            .as_deref()
+            .filter(|i| !i.is_empty())
+            .map(PathBuf::from)
+            .or(wallpaper)),
+        "color" => {
+            let role = config.lockscreen_color_role.as_deref().unwrap_or("background");
+            let hex = config.component_color("lockscreen", role);
+            let path = generated_dir().join(format!("solid-{}.png", color::strip_hash(&color::to_hex(&hex))));
+            if path.is_file() {
+                Ok(Some(path))
+            } else {
+                generate_solid(&hex).map(Some)
+            }
+        }
+        _ => Ok(wallpaper),
+    }
+}
+
+/// Blurred or dimmed copy of `source`, named after the file's key so it is only rendered once.
+/// Copies made for earlier wallpapers are removed.
+fn render_lockscreen(source: &Path, mode: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let key = thumbnails::file_key(source).ok_or("wallpaper not readable")?;
+    let prefix = format!("lockscreen-{}-", mode);
+    let path = generated_dir().join(format!("{}{:016x}.jpg", prefix, key));
+    if path.is_file() {
+        return Ok(path);
+    }
+
+    let full = thumbnails::decode_still(source).map_err(|e| e.to_string())?;
+    let image = if mode == "blurred" {
+        let small = full.resize(BLUR_WIDTH, u32::MAX, image::imageops::FilterType::Triangle).to_rgb8();
+        image::imageops::blur(&small, BLUR_SIGMA)
+    } else {
+        let (width, height) = target_size();
+        let mut image = full.resize_to_fill(width, height, image::imageops::FilterType::Triangle).to_rgb8();
+        for pixel in image.pixels_mut() {
+            for channel in pixel.0.iter_mut() {
+                *channel = (*channel as f32 * DIM_FACTOR).round() as u8;
+            }
+        }
+        image
+    };
+    drop(full);
+
+    fs::create_dir_all(generated_dir())?;
+    let tmp = path.with_extension("jpg.part");
+    image::DynamicImage::ImageRgb8(image).save_with_format(&tmp, image::ImageFormat::Jpeg)?;
+    fs::rename(&tmp, &path)?;
+
+    if let Ok(entries) = fs::read_dir(generated_dir()) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with("lockscreen-") && entry.path() != path {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+    Ok(path)
+}
+
+/// Settings the lock screen background is rendered from
+fn lockscreen_inputs(config: &ColorConfig) -> [Option<String>; 4] {
+    [
+        config.last_wallpaper.clone(),
+        config.lockscreen_background.clone(),
+        config.lockscreen_image.clone(),
+        config.lockscreen_color_role.clone(),
+    ]
+}
+
+/// Renders are retried at most this often when the settings keep changing under us
+const MAX_RENDER_ATTEMPTS: usize = 3;
+
+/// Bring `lockscreenBackgroundPath` in colors.json up to date with the wallpaper and
+/// lock screen settings. Returns true when it changed and was saved. Slow - call off the main thread.
+pub fn update_lockscreen_background() -> bool {
+    for _ in 0..MAX_RENDER_ATTEMPTS {
+        let config = ColorConfig::load();
+        let path = match lockscreen_background(&config) {
+            Ok(path) => path,
+            Err(e) => {
+                eprintln!("Failed to render lock screen background: {}", e);
+                config.last_wallpaper.as_deref().map(PathBuf::from)
+            }
+        };
+        let path = path.map(|p| p.to_string_lossy().to_string());
+
+        // Rendering takes a while - save on top of whatever was saved meanwhile,
+        // and start over if that changed what we rendered from
+        let mut fresh = ColorConfig::load();
+        if lockscreen_inputs(&fresh) != lockscreen_inputs(&config) {
+            continue;
+        }
+        if path == fresh.lockscreen_background_path {
+            return false;
+        }
+        fresh.lockscreen_background_path = path;
+        return fresh.save().is_ok();
+    }
+    false
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+
+/// Parse "#rgb", "#rgba", "#rrggbb" or "#rrggbbaa" into RGBA components (alpha 255 when absent).
+pub fn parse_hex_rgba(color: &str) -> Option<(u8, u8, u8, u8)> {
+    let hex = color.trim().trim_start_matches('#');
+    if !hex.is_ascii() {
+        return None;
+    }
+
+    // Handle 3/4-digit hex by doubling every digit
+    if hex.len() == 3 || hex.len() == 4 {
+        let expanded: String = hex.chars().flat_map(|c| [c, c]).collect();
+        return parse_hex_rgba(&expanded);
+    }
+
+    if hex.len() == 6 || hex.len() == 8 {
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+        return Some((channel(0)?, channel(2)?, channel(4)?, alpha));
+    }
+    None
+}
+
+/// Parse a hex color into its RGB components, ignoring any alpha.
+pub fn parse_hex(color: &str) -> Option<(u8, u8, u8)> {
+    parse_hex_rgba(color).map(|(r, g, b, _)| (r, g, b))
+}
+
+/// Alpha of a hex color as 0.0..=1.0 (1.0 for colors without alpha).
+pub fn alpha_of(color: &str) -> f64 {
+    parse_hex_rgba(color).map(|(_, _, _, a)| a as f64 / 255.0).unwrap_or(1.0)
+}
+
+/// Hex digits without the leading '#', normalized to lowercase "rrggbb" (alpha dropped).
+/// Falls back to the trimmed input when it can't be parsed.
+pub fn strip_hash(color: &str) -> String {
+    match parse_hex(color) {
+        Some((r, g, b)) => format!("{:02x}{:02x}{:02x}", r, g, b),
+        None => color.trim().trim_start_matches('#').to_string(),
+    }
+}
+
+/// Normalized "#rrggbb" form.
+pub fn to_hex(color: &str) -> String {
+    format!("#{}", strip_hash(color))
+}
+
+pub fn get_contrasting_text_color(hex: &str) -> String {
+    let hex = hex.trim().trim_start_matches('#');
+    
+    // Explicit handle for common white/black (optimization and safety)
+    if hex.eq_ignore_ascii_case("ffffff") || hex.eq_ignore_ascii_case("fff") || hex.eq_ignore_ascii_case("white") {
+        return "#000000".to_string();
+    }
+    if hex.eq_ignore_ascii_case("000000") || hex.eq_ignore_ascii_case("000") || hex.eq_ignore_ascii_case("black") {
+        return "#ffffff".to_string();
+    }
+
+    // Handles 3/4/6/8-digit hex; alpha is ignored
+    if let Some((r, g, b)) = parse_hex(hex) {
+        // Calculate brightness using standard formula
+        let brightness = (r as f32 * 0.299 + g as f32 * 0.587 + b as f32 * 0.114) / 255.0;
+        if brightness > 0.5 { // Lowered threshold slightly to 0.5 for better white detection
+            return "#000000".to_string(); // Bright background -> Dark text
+        }
+    }
+    "#ffffff".to_string() // Default to white text
+}
+
+/// Perceived brightness above 50% (same weighting as get_contrasting_text_color).
+pub fn is_light(color: &str) -> bool {
+    match parse_hex(color) {
+        Some((r, g, b)) => (r as f32 * 0.299 + g as f32 * 0.587 + b as f32 * 0.114) / 255.0 > 0.5,
+        None => false,
+    }
+}
+
+/// Convert RGB to HSL (hue in degrees, saturation/lightness in 0.0..=1.0).
+pub fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f64, f64, f64) {
+    let r = r as f64 / 255.0;
+    let g = g as f64 / 255.0;
+    let b = b as f64 / 255.0;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+
+    if (max - min).abs() < f64::EPSILON {
+        return (0.0, 0.0, l);
+    }
+
+    let d = max - min;
+    let s = if l > 0.5 { d / (2.0 - max - min) } else { d / (max + min) };
+    let h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h * 60.0, s, l)
+}
+
+pub fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    let to_u8 = |v: f64| (v * 255.0).round().clamp(0.0, 255.0) as u8;
+    if s <= 0.0 {
+        return (to_u8(l), to_u8(l), to_u8(l));
+    }
+
+    let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
+    let p = 2.0 * l - q;
+    let h = h.rem_euclid(360.0) / 360.0;
+    let hue = |mut t: f64| {
+        if t < 0.0 { t += 1.0; }
+        if t > 1.0 { t -= 1.0; }
+        if t < 1.0 / 6.0 {
+            p + (q - p) * 6.0 * t
+        } else if t < 0.5 {
+            q
+        } else if t < 2.0 / 3.0 {
+            p + (q - p) * (2.0 / 3.0 - t) * 6.0
+        } else {
+            p
+        }
+    };
+    (to_u8(hue(h + 1.0 / 3.0)), to_u8(hue(h)), to_u8(hue(h - 1.0 / 3.0)))
+}
+
+/// Shift HSL lightness by `amount` percentage points (negative darkens).
+pub fn adjust_lightness(color: &str, amount: f64) -> String {
+    let Some((r, g, b)) = parse_hex(color) else {
+        return color.to_string();
+    };
+    let (h, s, l) = rgb_to_hsl(r, g, b);
+    let (r, g, b) = hsl_to_rgb(h, s, (l + amount / 100.0).clamp(0.0, 1.0));
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+pub fn lighten(color: &str, amount: f64) -> String {
+    adjust_lightness(color, amount)
+}
+
+pub fn darken(color: &str, amount: f64) -> String {
+    adjust_lightness(color, -amount)
+}
+
+/// Linear RGB mix of `a` towards `b`; `t` = 0.0 gives `a`, 1.0 gives `b`.
+pub fn mix(a: &str, b: &str, t: f64) -> String {
+    let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (parse_hex(a), parse_hex(b)) else {
+        return a.to_string();
+    };
+    let t = t.clamp(0.0, 1.0);
+    let channel = |x: u8, y: u8| (x as f64 + (y as f64 - x as f64) * t).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(r1, r2), channel(g1, g2), channel(b1, b2))
+}
+
+/// Tonal steps of the accent scale. Positive values tint towards white,
+/// negative values shade towards black; 500 is the accent itself.
+pub const ACCENT_TONES: &[(&str, f64)] = &[
+    ("50", 0.90),
+    ("100", 0.80),
+    ("200", 0.60),
+    ("300", 0.40),
+    ("400", 0.20),
+    ("500", 0.0),
+    ("600", -0.15),
+    ("700", -0.30),
+    ("800", -0.45),
+    ("900", -0.60),
+];
+
+/// Build the accent scale (50..900) plus "hover" and "pressed" states.
+///
+/// Hover moves the accent slightly towards its foreground color and pressed
+/// moves it the other way, so both stay visible on light and dark accents.
+pub fn tonal_palette(accent: &str) -> BTreeMap<String, String> {
+    let accent = to_hex(accent);
+    let mut palette = BTreeMap::new();
+    for (step, amount) in ACCENT_TONES {
+        let tone = if *amount >= 0.0 {
+            mix(&accent, "#ffffff", *amount)
+        } else {
+            mix(&accent, "#000000", -amount)
+        };
+        palette.insert(step.to_string(), tone);
+    }
+
+    let fg = get_contrasting_text_color(&accent);
+    let away = if fg == "#ffffff" { "#000000" } else { "#ffffff" };
+    palette.insert("hover".to_string(), mix(&accent, &fg, 0.12));
+    palette.insert("pressed".to_string(), mix(&accent, away, 0.18));
+    palette
+}
@@ -1,9 +1,92 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::fs;
 use dirs;
 
+use crate::core::color;
+
+/// Corner radius (px) of the "Rounded" style. Fuse's CSS radii are designed around this value.
+pub const DEFAULT_CORNER_RADIUS: u8 = 6;
+pub const MAX_CORNER_RADIUS: u8 = 24;
+
+/// UI and text scale limits (percent). Values are stored in 5% steps.
+pub const MIN_SCALE: u8 = 50;
+pub const MAX_SCALE: u8 = 200;
+pub const SCALE_STEP: u8 = 5;
+
+/// Subdirectory levels scanned below each wallpaper folder.
+pub const DEFAULT_WALLPAPER_DEPTH: u8 = 1;
+pub const MAX_WALLPAPER_DEPTH: u8 = 5;
+
+/// Clamp a scale percentage to MIN_SCALE..=MAX_SCALE and snap it to SCALE_STEP.
+pub fn clamp_scale(value: u8) -> u8 {
+    let snapped = ((value as f64 / SCALE_STEP as f64).round() * SCALE_STEP as f64) as u8;
+    snapped.clamp(MIN_SCALE, MAX_SCALE)
+}
+
+/// Expand "~" and a leading "~/" to the home directory. Other paths ("~user/x" included)
+/// are returned as they are.
+pub fn expand_home(path: &str) -> PathBuf {
+    let rest = match path {
+        "~" => "",
+        _ => match path.strip_prefix("~/") {
+            Some(rest) => rest,
+            None => return PathBuf::from(path),
+        },
+    };
+    match dirs::home_dir() {
+        Some(home) => home.join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Optional per-component replacements for the five global color roles.
+/// A missing role means "use global".
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColorOverrides {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+}
+
+impl ColorOverrides {
+    pub fn get(&self, role: &str) -> Option<&String> {
+        match role {
+            "background" => self.background.as_ref(),
+            "primary" => self.primary.as_ref(),
+            "secondary" => self.secondary.as_ref(),
+            "text" => self.text.as_ref(),
+            "accent" => self.accent.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, role: &str, color: Option<&str>) {
+        let color = color.map(|c| c.to_string());
+        match role {
+            "background" => self.background = color,
+            "primary" => self.primary = color,
+            "secondary" => self.secondary = color,
+            "text" => self.text = color,
+            "accent" => self.accent = color,
+            _ => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColorConfig {
     pub background: String,
@@ -91,6 +174,92 @@ pub struct ColorConfig {
     pub screensaver_widgets_enabled: Option<bool>,
     #[serde(rename = "sidebarBatteryEnabled", skip_serializing_if = "Option::is_none")]
     pub sidebar_battery_enabled: Option<bool>,
+    #[serde(rename = "exportKitty", skip_serializing_if = "Option::is_none")]
+    pub export_kitty: Option<bool>,
+    #[serde(rename = "exportAlacritty", skip_serializing_if = "Option::is_none")]
+    pub export_alacritty: Option<bool>,
+    #[serde(rename = "exportFoot", skip_serializing_if = "Option::is_none")]
+    pub export_foot: Option<bool>,
+    #[serde(rename = "exportGtk", skip_serializing_if = "Option::is_none")]
+    pub export_gtk: Option<bool>,
+    #[serde(rename = "exportHyprland", skip_serializing_if = "Option::is_none")]
+    pub export_hyprland: Option<bool>,
+    #[serde(rename = "accentPalette", skip_serializing_if = "Option::is_none")]
+    pub accent_palette: Option<BTreeMap<String, String>>,
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(rename = "warning", skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    #[serde(rename = "success", skip_serializing_if = "Option::is_none")]
+    pub success: Option<String>,
+    #[serde(rename = "info", skip_serializing_if = "Option::is_none")]
+    pub info: Option<String>,
+    #[serde(rename = "themeMode", skip_serializing_if = "Option::is_none")]
+    pub theme_mode: Option<String>,
+    #[serde(rename = "themeSchedule", skip_serializing_if = "Option::is_none")]
+    pub theme_schedule: Option<String>,
+    #[serde(rename = "lightTime", skip_serializing_if = "Option::is_none")]
+    pub light_time: Option<String>,
+    #[serde(rename = "darkTime", skip_serializing_if = "Option::is_none")]
+    pub dark_time: Option<String>,
+    #[serde(rename = "latitude", skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(rename = "longitude", skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    #[serde(rename = "textScale", skip_serializing_if = "Option::is_none")]
+    pub text_scale: Option<u8>,
+    #[serde(rename = "sidebarOpacity", skip_serializing_if = "Option::is_none")]
+    pub sidebar_opacity: Option<u8>,
+    #[serde(rename = "dashboardOpacity", skip_serializing_if = "Option::is_none")]
+    pub dashboard_opacity: Option<u8>,
+    #[serde(rename = "notificationOpacity", skip_serializing_if = "Option::is_none")]
+    pub notification_opacity: Option<u8>,
+    #[serde(rename = "lockscreenOpacity", skip_serializing_if = "Option::is_none")]
+    pub lockscreen_opacity: Option<u8>,
+    #[serde(rename = "blurEnabled", skip_serializing_if = "Option::is_none")]
+    pub blur_enabled: Option<bool>,
+    #[serde(rename = "componentColors", skip_serializing_if = "Option::is_none")]
+    pub component_colors: Option<BTreeMap<String, ColorOverrides>>,
+    #[serde(rename = "uiFont", skip_serializing_if = "Option::is_none")]
+    pub ui_font: Option<String>,
+    #[serde(rename = "uiFontSize", skip_serializing_if = "Option::is_none")]
+    pub ui_font_size: Option<u8>,
+    #[serde(rename = "monoFont", skip_serializing_if = "Option::is_none")]
+    pub mono_font: Option<String>,
+    #[serde(rename = "monoFontSize", skip_serializing_if = "Option::is_none")]
+    pub mono_font_size: Option<u8>,
+    #[serde(rename = "iconFont", skip_serializing_if = "Option::is_none")]
+    pub icon_font: Option<String>,
+    #[serde(rename = "wallpaperDirs", skip_serializing_if = "Option::is_none")]
+    pub wallpaper_dirs: Option<Vec<String>>,
+    #[serde(rename = "wallpaperDepth", skip_serializing_if = "Option::is_none")]
+    pub wallpaper_depth: Option<u8>,
+    #[serde(rename = "wallpaperShowHidden", skip_serializing_if = "Option::is_none")]
+    pub wallpaper_show_hidden: Option<bool>,
+    #[serde(rename = "slideshowEnabled", skip_serializing_if = "Option::is_none")]
+    pub slideshow_enabled: Option<bool>,
+    #[serde(rename = "slideshowInterval", skip_serializing_if = "Option::is_none")]
+    pub slideshow_interval: Option<u32>,
+    #[serde(rename = "slideshowOrder", skip_serializing_if = "Option::is_none")]
+    pub slideshow_order: Option<String>,
+    #[serde(rename = "slideshowSource", skip_serializing_if = "Option::is_none")]
+    pub slideshow_source: Option<String>,
+    #[serde(rename = "monitorWallpapers", skip_serializing_if = "Option::is_none")]
+    pub monitor_wallpapers: Option<BTreeMap<String, String>>,
+    #[serde(rename = "wallpaperFit", skip_serializing_if = "Option::is_none")]
+    pub wallpaper_fit: Option<String>,
+    #[serde(rename = "wallpaperImportMode", skip_serializing_if = "Option::is_none")]
+    pub wallpaper_import_mode: Option<String>,
+    #[serde(rename = "lockscreenBackground", skip_serializing_if = "Option::is_none")]
+    pub lockscreen_background: Option<String>,
+    #[serde(rename = "lockscreenImage", skip_serializing_if = "Option::is_none")]
+    pub lockscreen_image: Option<String>,
+    #[serde(rename = "lockscreenColorRole", skip_serializing_if = "Option::is_none")]
+    pub lockscreen_color_role: Option<String>,
+    #[serde(rename = "lockscreenBackgroundPath", skip_serializing_if = "Option::is_none")]
+    pub lockscreen_background_path: Option<String>,
+    #[serde(rename = "lastWallpaperType", skip_serializing_if = "Option::is_none")]
+    pub last_wallpaper_type: Option<String>,
 }
 
 impl Default for ColorConfig {
@@ -127,7 +296,7 @@ impl Default for ColorConfig {
             scripts_use_lockscreen: Some(false),
             notification_position: Some("top".to_string()),
             notification_rounding: Some("standard".to_string()),
-            quickshell_border_radius: Some(0),
+            quickshell_border_radius: Some(DEFAULT_CORNER_RADIUS),
             notification_sound: Some("message.oga".to_string()),
             weather_location: Some("London".to_string()),
             floating_dashboard: Some(true),
@@ -142,6 +311,49 @@ impl Default for ColorConfig {
             dynamic_sidebar_background: Some(false),
             screensaver_widgets_enabled: Some(true),
             sidebar_battery_enabled: Some(true),
+            export_kitty: Some(false),
+            export_alacritty: Some(false),
+            export_foot: Some(false),
+            export_gtk: Some(false),
+            export_hyprland: Some(false),
+            accent_palette: Some(color::tonal_palette("#4a9eff")),
+            error: Some("#ff6b6b".to_string()),
+            warning: Some("#ffb454".to_string()),
+            success: Some("#6bd968".to_string()),
+            info: Some("#62a0ea".to_string()),
+            theme_mode: Some("manual".to_string()),
+            theme_schedule: Some("times".to_string()),
+            light_time: Some("07:00".to_string()),
+            dark_time: Some("19:00".to_string()),
+            latitude: None,
+            longitude: None,
+            text_scale: Some(100),
+            sidebar_opacity: Some(100),
+            dashboard_opacity: Some(100),
+            notification_opacity: Some(100),
+            lockscreen_opacity: Some(100),
+            blur_enabled: Some(false),
+            component_colors: None,
+            ui_font: None,
+            ui_font_size: None,
+            mono_font: None,
+            mono_font_size: None,
+            icon_font: None,
+            wallpaper_dirs: None,
+            wallpaper_depth: None,
+            wallpaper_show_hidden: None,
+            slideshow_enabled: None,
+            slideshow_interval: None,
+            slideshow_order: None,
+            slideshow_source: None,
+            monitor_wallpapers: None,
+            wallpaper_fit: None,
+            wallpaper_import_mode: None,
+            lockscreen_background: None,
+            lockscreen_image: None,
+            lockscreen_color_role: None,
+            lockscreen_background_path: None,
+            last_wallpaper_type: None,
         }
     }
 }
@@ -219,9 +431,16 @@ impl ColorConfig {
                                 config.text = txt.to_string();
                                 config.accent = acc.to_string();
                             }
+                            // Status colors are optional in presets - keep the current ones when missing
+                            for role in ["error", "warning", "success", "info"] {
+                                if let Some(color) = preset.get(role).and_then(|v| v.as_str()) {
+                                    config.set_status_color(role, color);
+                                }
+                            }
                         }
                     }
                 }
+                config.refresh_accent_palette();
                 config
             }
             Err(_) => Self::default(),
@@ -450,12 +669,9 @@ impl ColorConfig {
             cmd.arg("");
         }
 
-        // Argument 32: quickshellBorderRadius (0=disabled, 2-8 typical)
-        if let Some(val) = self.quickshell_border_radius {
-            cmd.arg(val.to_string());
-        } else {
-            cmd.arg("");
-        }
+        // Argument 32: quickshellBorderRadius (0=disabled, 2-8 typical) - always the resolved
+        // radius, so Quickshell shows the same corners as Fuse
+        cmd.arg(self.corner_radius().to_string());
 
         // Argument 33: notificationSound
         if let Some(ref sound) = self.notification_sound {
@@ -546,12 +762,316 @@ impl ColorConfig {
         } else {
             cmd.arg("");
         }
-        
+
+        // Argument 48: exportKitty (true/false)
+        if let Some(enabled) = self.export_kitty {
+            cmd.arg(if enabled { "true" } else { "false" });
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 49: exportAlacritty (true/false)
+        if let Some(enabled) = self.export_alacritty {
+            cmd.arg(if enabled { "true" } else { "false" });
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 50: exportFoot (true/false)
+        if let Some(enabled) = self.export_foot {
+            cmd.arg(if enabled { "true" } else { "false" });
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 51: exportGtk (true/false)
+        if let Some(enabled) = self.export_gtk {
+            cmd.arg(if enabled { "true" } else { "false" });
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 52: exportHyprland (true/false)
+        if let Some(enabled) = self.export_hyprland {
+            cmd.arg(if enabled { "true" } else { "false" });
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 53: accentPalette (JSON object, generated from accent)
+        if let Some(ref palette) = self.accent_palette {
+            cmd.arg(serde_json::to_string(palette).unwrap_or_default());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 54: error (status color)
+        if let Some(ref color) = self.error {
+            cmd.arg(color);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 55: warning (status color)
+        if let Some(ref color) = self.warning {
+            cmd.arg(color);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 56: success (status color)
+        if let Some(ref color) = self.success {
+            cmd.arg(color);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 57: info (status color)
+        if let Some(ref color) = self.info {
+            cmd.arg(color);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 58: themeMode (manual/auto)
+        if let Some(ref val) = self.theme_mode {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 59: themeSchedule (times/sun)
+        if let Some(ref val) = self.theme_schedule {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 60: lightTime (HH:MM)
+        if let Some(ref val) = self.light_time {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 61: darkTime (HH:MM)
+        if let Some(ref val) = self.dark_time {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 62: latitude (degrees, for sunrise/sunset)
+        if let Some(val) = self.latitude {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 63: longitude (degrees, for sunrise/sunset)
+        if let Some(val) = self.longitude {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 64: textScale (percent, 50-200)
+        if let Some(val) = self.text_scale {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 65: sidebarOpacity (percent, sidebar background)
+        if let Some(val) = self.sidebar_opacity {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 66: dashboardOpacity (percent, dashboard background)
+        if let Some(val) = self.dashboard_opacity {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 67: notificationOpacity (percent, notifications background)
+        if let Some(val) = self.notification_opacity {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 68: lockscreenOpacity (percent, lock screen background)
+        if let Some(val) = self.lockscreen_opacity {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 69: blurEnabled (true/false)
+        if let Some(enabled) = self.blur_enabled {
+            cmd.arg(if enabled { "true" } else { "false" });
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 70: componentColors (JSON object: component -> role overrides)
+        if let Some(ref overrides) = self.component_colors {
+            cmd.arg(serde_json::to_string(overrides).unwrap_or_default());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 71: uiFont (family name, empty = system default)
+        if let Some(ref val) = self.ui_font {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 72: uiFontSize (pt)
+        if let Some(val) = self.ui_font_size {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 73: monoFont (family name)
+        if let Some(ref val) = self.mono_font {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 74: monoFontSize (pt)
+        if let Some(val) = self.mono_font_size {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 75: iconFont (Nerd Font family for glyph icons)
+        if let Some(ref val) = self.icon_font {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 76: wallpaperDirs (JSON array of directories)
+        if let Some(ref dirs) = self.wallpaper_dirs {
+            cmd.arg(serde_json::to_string(dirs).unwrap_or_default());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 77: wallpaperDepth (subdirectory levels to scan)
+        if let Some(val) = self.wallpaper_depth {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 78: wallpaperShowHidden (true/false)
+        if let Some(enabled) = self.wallpaper_show_hidden {
+            cmd.arg(if enabled { "true" } else { "false" });
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 79: slideshowEnabled (true/false)
+        if let Some(enabled) = self.slideshow_enabled {
+            cmd.arg(if enabled { "true" } else { "false" });
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 80: slideshowInterval (minutes)
+        if let Some(val) = self.slideshow_interval {
+            cmd.arg(val.to_string());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 81: slideshowOrder (shuffle/ordered)
+        if let Some(ref val) = self.slideshow_order {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 82: slideshowSource (folder, empty = all wallpaper folders)
+        if let Some(ref val) = self.slideshow_source {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 83: monitorWallpapers (JSON object: output name -> wallpaper path)
+        if let Some(ref wallpapers) = self.monitor_wallpapers {
+            cmd.arg(serde_json::to_string(wallpapers).unwrap_or_default());
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 84: wallpaperFit (fill/fit/center/tile/stretch)
+        if let Some(ref val) = self.wallpaper_fit {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 85: wallpaperImportMode (copy/link)
+        if let Some(ref val) = self.wallpaper_import_mode {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 86: lockscreenBackground (wallpaper/blurred/dimmed/image/color)
+        if let Some(ref val) = self.lockscreen_background {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 87: lockscreenImage (separate lock screen image)
+        if let Some(ref val) = self.lockscreen_image {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 88: lockscreenColorRole (palette role for a solid background)
+        if let Some(ref val) = self.lockscreen_color_role {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 89: lockscreenBackgroundPath (rendered image the lock screen shows)
+        if let Some(ref val) = self.lockscreen_background_path {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
+        // Argument 90: lastWallpaperType (image/animated/video)
+        if let Some(ref val) = self.last_wallpaper_type {
+            cmd.arg(val);
+        } else {
+            cmd.arg("");
+        }
+
         let output = cmd.output()?;
         if !output.status.success() {
             // Fallback to direct save on error
             return self.save_direct();
         }
+
+        // An older script ignores arguments it doesn't know - add those fields ourselves
+        self.fill_missing_fields()?;
         
         // Ensure file is synced to disk
         use std::fs::OpenOptions;
@@ -562,6 +1082,37 @@ impl ColorConfig {
         Ok(())
     }
 
+    /// Add any of our fields the save script left out of colors.json, keeping everything
+    /// it did write (including keys Fuse doesn't know, like presets).
+    fn fill_missing_fields(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Self::get_config_path();
+        let Ok(Value::Object(mut saved)) = serde_json::from_str::<Value>(&fs::read_to_string(&path)?) else {
+            return Ok(());
+        };
+        let Value::Object(ours) = serde_json::to_value(self)? else {
+            return Ok(());
+        };
+        let mut missing = false;
+        for (key, value) in ours {
+            if !saved.contains_key(&key) {
+                saved.insert(key, value);
+                missing = true;
+            }
+        }
+        if !missing {
+            return Ok(());
+        }
+
+        // Same atomic write as save_direct
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(&Value::Object(saved))?)?;
+        if let Ok(file) = fs::OpenOptions::new().write(true).open(&temp_path) {
+            file.sync_all()?;
+        }
+        fs::rename(&temp_path, &path)?;
+        Ok(())
+    }
+
     fn save_direct(&self) -> Result<(), Box<dyn std::error::Error>> {
         let path = Self::get_config_path();
         
@@ -602,6 +1153,20 @@ impl ColorConfig {
         self.secondary = secondary.to_string();
         self.text = text.to_string();
         self.accent = accent.to_string();
+        self.refresh_accent_palette();
+    }
+
+    /// Regenerate the tonal accent scale (accent-50 … accent-900, hover, pressed) from `accent`.
+    pub fn refresh_accent_palette(&mut self) {
+        self.accent_palette = Some(color::tonal_palette(&self.accent));
+    }
+
+    /// Shade of the accent scale by key ("50" … "900", "hover", "pressed").
+    pub fn accent_tone(&self, key: &str) -> String {
+        self.accent_palette
+            .as_ref()
+            .and_then(|p| p.get(key).cloned())
+            .unwrap_or_else(|| self.accent.clone())
     }
 
     pub fn set_wallpaper(&mut self, wallpaper_path: &str) {
@@ -637,7 +1202,7 @@ impl ColorConfig {
     }
 
     pub fn set_ui_scale(&mut self, value: u8) {
-        self.ui_scale = Some(value);
+        self.ui_scale = Some(clamp_scale(value));
     }
 
     pub fn set_dashboard_tile_left(&mut self, value: &str) {
@@ -712,6 +1277,40 @@ impl ColorConfig {
         self.quickshell_border_radius = Some(value);
     }
 
+    /// Corner radius shared by Fuse and Quickshell. quickshell_border_radius is the source of truth;
+    /// older configs that only have rounding = "rounded" (with radius 0 or unset) get the default.
+    pub fn corner_radius(&self) -> u8 {
+        match (self.rounding.as_deref(), self.quickshell_border_radius) {
+            (Some("sharp"), _) => 0,
+            (_, Some(radius)) if radius > 0 => radius.min(MAX_CORNER_RADIUS),
+            _ => DEFAULT_CORNER_RADIUS,
+        }
+    }
+
+    /// Configs written before the radius slider may store 0 without rounding = "sharp", which Fuse
+    /// shows as the default radius but Quickshell reads as 0. Save the resolved radius once so
+    /// both agree. Returns true when colors.json was rewritten.
+    pub fn migrate_corner_radius() -> bool {
+        let stored = fs::read_to_string(Self::get_config_path())
+            .ok()
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            .and_then(|root| root.get("quickshellBorderRadius").and_then(|v| v.as_u64()));
+        let mut config = Self::load();
+        let radius = config.corner_radius();
+        if stored == Some(radius as u64) {
+            return false;
+        }
+        config.set_corner_radius(radius);
+        config.save().is_ok()
+    }
+
+    /// Set the corner radius, keeping the legacy rounding string in sync.
+    pub fn set_corner_radius(&mut self, radius: u8) {
+        let radius = radius.min(MAX_CORNER_RADIUS);
+        self.set_quickshell_border_radius(radius);
+        self.set_rounding(if radius == 0 { "sharp" } else { "rounded" });
+    }
+
     pub fn set_notification_sound(&mut self, sound: &str) {
         self.notification_sound = Some(sound.to_string());
     }
@@ -770,7 +1369,241 @@ impl ColorConfig {
         self.sidebar_battery_enabled = Some(enabled);
     }
 
-    /// Set GTK_SCALE_FACTOR from ui_scale (75 -> 0.75, 100 -> 1.0, 125 -> 1.25). Call before gtk_init.
+    pub fn set_export_kitty(&mut self, enabled: bool) {
+        self.export_kitty = Some(enabled);
+    }
+
+    pub fn set_export_alacritty(&mut self, enabled: bool) {
+        self.export_alacritty = Some(enabled);
+    }
+
+    pub fn set_export_foot(&mut self, enabled: bool) {
+        self.export_foot = Some(enabled);
+    }
+
+    pub fn set_export_gtk(&mut self, enabled: bool) {
+        self.export_gtk = Some(enabled);
+    }
+
+    pub fn set_export_hyprland(&mut self, enabled: bool) {
+        self.export_hyprland = Some(enabled);
+    }
+
+    pub fn set_status_colors(&mut self, error: &str, warning: &str, success: &str, info: &str) {
+        self.error = Some(error.to_string());
+        self.warning = Some(warning.to_string());
+        self.success = Some(success.to_string());
+        self.info = Some(info.to_string());
+    }
+
+    /// Set a single status color by role name ("error", "warning", "success", "info").
+    pub fn set_status_color(&mut self, role: &str, color: &str) {
+        let color = Some(color.to_string());
+        match role {
+            "error" => self.error = color,
+            "warning" => self.warning = color,
+            "success" => self.success = color,
+            "info" => self.info = color,
+            _ => {}
+        }
+    }
+
+    /// Status color by role, falling back to the built-in default for configs written before status colors existed.
+    pub fn status_color(&self, role: &str) -> String {
+        let (value, fallback) = match role {
+            "error" => (&self.error, "#ff6b6b"),
+            "warning" => (&self.warning, "#ffb454"),
+            "success" => (&self.success, "#6bd968"),
+            _ => (&self.info, "#62a0ea"),
+        };
+        value.clone().unwrap_or_else(|| fallback.to_string())
+    }
+
+    pub fn set_theme_mode(&mut self, mode: &str) {
+        self.theme_mode = Some(mode.to_string());
+    }
+
+    pub fn set_theme_schedule(&mut self, schedule: &str) {
+        self.theme_schedule = Some(schedule.to_string());
+    }
+
+    pub fn set_theme_times(&mut self, light_time: &str, dark_time: &str) {
+        self.light_time = Some(light_time.to_string());
+        self.dark_time = Some(dark_time.to_string());
+    }
+
+    pub fn set_location(&mut self, latitude: f64, longitude: f64) {
+        self.latitude = Some(latitude.clamp(-90.0, 90.0));
+        self.longitude = Some(longitude.clamp(-180.0, 180.0));
+    }
+
+    pub fn set_text_scale(&mut self, value: u8) {
+        self.text_scale = Some(clamp_scale(value));
+    }
+
+    /// Background opacity (percent) of a Quickshell surface: "sidebar", "dashboard", "notifications" or "lockscreen".
+    pub fn surface_opacity(&self, surface: &str) -> u8 {
+        let value = match surface {
+            "sidebar" => self.sidebar_opacity,
+            "dashboard" => self.dashboard_opacity,
+            "notifications" => self.notification_opacity,
+            "lockscreen" => self.lockscreen_opacity,
+            _ => None,
+        };
+        value.unwrap_or(100).min(100)
+    }
+
+    pub fn set_surface_opacity(&mut self, surface: &str, value: u8) {
+        let value = Some(value.min(100));
+        match surface {
+            "sidebar" => self.sidebar_opacity = value,
+            "dashboard" => self.dashboard_opacity = value,
+            "notifications" => self.notification_opacity = value,
+            "lockscreen" => self.lockscreen_opacity = value,
+            _ => {}
+        }
+    }
+
+    pub fn set_blur_enabled(&mut self, enabled: bool) {
+        self.blur_enabled = Some(enabled);
+    }
+
+    /// Color of `role` for a Quickshell component: its override when set, otherwise the global role.
+    pub fn component_color(&self, component: &str, role: &str) -> String {
+        self.component_colors
+            .as_ref()
+            .and_then(|all| all.get(component))
+            .and_then(|overrides| overrides.get(role).cloned())
+            .unwrap_or_else(|| self.global_color(role))
+    }
+
+    /// Override of `role` for `component`, None when it follows the global palette.
+    pub fn component_override(&self, component: &str, role: &str) -> Option<String> {
+        self.component_colors.as_ref()?.get(component)?.get(role).cloned()
+    }
+
+    /// Set (Some) or clear (None, "use global") one role override of a component.
+    pub fn set_component_color(&mut self, component: &str, role: &str, color: Option<&str>) {
+        let all = self.component_colors.get_or_insert_with(BTreeMap::new);
+        let overrides = all.entry(component.to_string()).or_default();
+        overrides.set(role, color);
+        if overrides.is_empty() {
+            all.remove(component);
+        }
+        if all.is_empty() {
+            self.component_colors = None;
+        }
+    }
+
+    pub fn global_color(&self, role: &str) -> String {
+        match role {
+            "background" => self.background.clone(),
+            "primary" => self.primary.clone(),
+            "secondary" => self.secondary.clone(),
+            "text" => self.text.clone(),
+            _ => self.accent.clone(),
+        }
+    }
+
+    /// Font family for `kind` ("ui", "mono" or "icon"); None = system default.
+    pub fn set_font(&mut self, kind: &str, family: Option<&str>) {
+        let family = family.filter(|f| !f.trim().is_empty()).map(|f| f.to_string());
+        match kind {
+            "ui" => self.ui_font = family,
+            "mono" => self.mono_font = family,
+            "icon" => self.icon_font = family,
+            _ => {}
+        }
+    }
+
+    pub fn set_font_size(&mut self, kind: &str, size: u8) {
+        let size = Some(size.clamp(6, 48));
+        match kind {
+            "ui" => self.ui_font_size = size,
+            "mono" => self.mono_font_size = size,
+            _ => {}
+        }
+    }
+
+    /// Directories scanned for wallpapers; an empty list goes back to the default one.
+    pub fn set_wallpaper_dirs(&mut self, dirs: Vec<String>) {
+        self.wallpaper_dirs = if dirs.is_empty() { None } else { Some(dirs) };
+    }
+
+    pub fn set_wallpaper_depth(&mut self, depth: u8) {
+        self.wallpaper_depth = Some(depth.min(MAX_WALLPAPER_DEPTH));
+    }
+
+    pub fn set_wallpaper_show_hidden(&mut self, enabled: bool) {
+        self.wallpaper_show_hidden = Some(enabled);
+    }
+
+    pub fn set_slideshow_enabled(&mut self, enabled: bool) {
+        self.slideshow_enabled = Some(enabled);
+    }
+
+    pub fn set_slideshow_interval(&mut self, minutes: u32) {
+        self.slideshow_interval = Some(minutes.max(1));
+    }
+
+    pub fn set_slideshow_order(&mut self, order: &str) {
+        self.slideshow_order = Some(order.to_string());
+    }
+
+    /// Folder the slideshow picks from; None uses every wallpaper folder.
+    pub fn set_slideshow_source(&mut self, source: Option<&str>) {
+        self.slideshow_source = source.filter(|s| !s.is_empty()).map(|s| s.to_string());
+    }
+
+    /// Wallpaper for one output; None goes back to the main wallpaper there.
+    pub fn set_monitor_wallpaper(&mut self, output: &str, wallpaper_path: Option<&str>) {
+        let wallpapers = self.monitor_wallpapers.get_or_insert_with(BTreeMap::new);
+        match wallpaper_path {
+            Some(path) => {
+                wallpapers.insert(output.to_string(), path.to_string());
+            }
+            None => {
+                wallpapers.remove(output);
+            }
+        }
+        if wallpapers.is_empty() {
+            self.monitor_wallpapers = None;
+        }
+    }
+
+    /// Wallpaper shown on `output`: its own one if assigned, otherwise the main wallpaper.
+    pub fn wallpaper_for(&self, output: &str) -> Option<String> {
+        self.monitor_wallpapers
+            .as_ref()
+            .and_then(|w| w.get(output).cloned())
+            .or_else(|| self.last_wallpaper.clone())
+    }
+
+    pub fn set_wallpaper_fit(&mut self, fit: &str) {
+        self.wallpaper_fit = Some(fit.to_string());
+    }
+
+    pub fn set_wallpaper_import_mode(&mut self, mode: &str) {
+        self.wallpaper_import_mode = Some(mode.to_string());
+    }
+
+    pub fn set_lockscreen_background(&mut self, mode: &str) {
+        self.lockscreen_background = Some(mode.to_string());
+    }
+
+    pub fn set_lockscreen_image(&mut self, path: &str) {
+        self.lockscreen_image = Some(path.to_string());
+    }
+
+    pub fn set_lockscreen_color_role(&mut self, role: &str) {
+        self.lockscreen_color_role = Some(role.to_string());
+    }
+
+    pub fn set_wallpaper_type(&mut self, kind: &str) {
+        self.last_wallpaper_type = Some(kind.to_string());
+    }
+
+    /// Set GTK_SCALE_FACTOR from ui_scale (75 -> 0.75, 130 -> 1.3, 200 -> 2.0). Call before gtk_init.
     pub fn apply_scale_env_to_process() {
         let config = Self::load();
         Self::apply_scale_env_from_config(&config);
@@ -779,13 +1612,8 @@ impl ColorConfig {
     /// Set GTK_SCALE_FACTOR from existing config. Use when config is already loaded.
     pub fn apply_scale_env_from_config(config: &Self) {
         if let Some(scale) = config.ui_scale {
-            let factor = match scale {
-                75 => "0.75",
-                100 => "1.0",
-                125 => "1.25",
-                _ => "1.0",
-            };
-            std::env::set_var("GTK_SCALE_FACTOR", factor);
+            let factor = clamp_scale(scale) as f64 / 100.0;
+            std::env::set_var("GTK_SCALE_FACTOR", format!("{}", factor));
         }
     }
 }
@@ -0,0 +1,95 @@
+use gtk4::gio;
+use gtk4::prelude::*;
+use std::path::{Path, PathBuf};
+
+use crate::core::thumbnails;
+use crate::core::wallpapers::HueFamily;
+
+/// Max differing bits for two images to count as the same picture
+const MAX_AHASH_DISTANCE: u32 = 6;
+const MAX_DHASH_DISTANCE: u32 = 6;
+
+/// One copy within a group of duplicates.
+#[derive(Debug, Clone)]
+pub struct DuplicateCopy {
+    pub path: PathBuf,
+    pub width: u32,
+    pub height: u32,
+    /// File size in bytes
+    pub size: u64,
+}
+
+impl DuplicateCopy {
+    pub fn pixels(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+}
+
+/// Flatter images than this (luma std. deviation) hash alike whatever their color - never grouped
+const MIN_LUMA_SPREAD: f64 = 4.0;
+
+struct Candidate {
+    copy: DuplicateCopy,
+    ahash: u64,
+    dhash: u64,
+    hue: HueFamily,
+}
+
+impl Candidate {
+    fn matches(&self, other: &Candidate) -> bool {
+        self.hue == other.hue
+            && (self.ahash ^ other.ahash).count_ones() <= MAX_AHASH_DISTANCE
+            && (self.dhash ^ other.dhash).count_ones() <= MAX_DHASH_DISTANCE
+    }
+}
+
+/// Group wallpapers that show the same picture. Each group is sorted best copy first
+/// (most pixels, then largest file). Slow - hashes every uncached image; call off the main thread.
+pub fn find_duplicates(wallpapers: &[PathBuf]) -> Vec<Vec<DuplicateCopy>> {
+    let mut candidates = Vec::new();
+    for path in wallpapers {
+        let Some(info) = thumbnails::image_info(path) else {
+            continue;
+        };
+        let (Some(ahash), Some(dhash)) = (info.ahash, info.dhash) else {
+            continue;
+        };
+        // Solid colors and near-flat images carry no shape to compare
+        if ahash == 0 || info.luma_spread.unwrap_or(0.0) < MIN_LUMA_SPREAD {
+            continue;
+        }
+        let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        candidates.push(Candidate {
+            copy: DuplicateCopy { path: path.clone(), width: info.width, height: info.height, size },
+            ahash,
+            dhash,
+            hue: HueFamily::of(info.hue),
+        });
+    }
+
+    // Each image is compared with the first copy of a group only, so A~B and B~C
+    // don't pull in a C that looks nothing like A
+    let mut groups: Vec<Vec<Candidate>> = Vec::new();
+    for candidate in candidates {
+        match groups.iter_mut().find(|group| group[0].matches(&candidate)) {
+            Some(group) => group.push(candidate),
+            None => groups.push(vec![candidate]),
+        }
+    }
+
+    let mut groups: Vec<Vec<DuplicateCopy>> = groups
+        .into_iter()
+        .filter(|g| g.len() > 1)
+        .map(|g| g.into_iter().map(|c| c.copy).collect())
+        .collect();
+    for group in groups.iter_mut() {
+        group.sort_by(|a, b| b.pixels().cmp(&a.pixels()).then(b.size.cmp(&a.size)));
+    }
+    groups.sort_by(|a, b| a[0].path.cmp(&b[0].path));
+    groups
+}
+
+/// Move a file to the desktop trash (recoverable, unlike deleting).
+pub fn trash(path: &Path) -> Result<(), gtk4::glib::Error> {
+    gio::File::for_path(path).trash(gio::Cancellable::NONE)
+}
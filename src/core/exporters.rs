@@ -0,0 +1,277 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use dirs;
+
+use crate::core::color;
+use crate::core::config::ColorConfig;
+
+// Markers delimiting the block we own inside the user's gtk.css
+const GTK_BLOCK_START: &str = "/* >>> alloy colors (managed by Fuse) >>> */";
+const GTK_BLOCK_END: &str = "/* <<< alloy colors <<< */";
+
+/// Built-in palette exporters. Each one has its own on/off switch in ColorConfig.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exporter {
+    Kitty,
+    Alacritty,
+    Foot,
+    Gtk,
+    Hyprland,
+}
+
+pub const ALL_EXPORTERS: &[Exporter] = &[
+    Exporter::Kitty,
+    Exporter::Alacritty,
+    Exporter::Foot,
+    Exporter::Gtk,
+    Exporter::Hyprland,
+];
+
+impl Exporter {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Exporter::Kitty => "Kitty",
+            Exporter::Alacritty => "Alacritty",
+            Exporter::Foot => "Foot",
+            Exporter::Gtk => "GTK 4 (gtk.css)",
+            Exporter::Hyprland => "Hyprland Borders",
+        }
+    }
+
+    pub fn is_enabled(&self, config: &ColorConfig) -> bool {
+        match self {
+            Exporter::Kitty => config.export_kitty.unwrap_or(false),
+            Exporter::Alacritty => config.export_alacritty.unwrap_or(false),
+            Exporter::Foot => config.export_foot.unwrap_or(false),
+            Exporter::Gtk => config.export_gtk.unwrap_or(false),
+            Exporter::Hyprland => config.export_hyprland.unwrap_or(false),
+        }
+    }
+
+    pub fn set_enabled(&self, config: &mut ColorConfig, enabled: bool) {
+        match self {
+            Exporter::Kitty => config.set_export_kitty(enabled),
+            Exporter::Alacritty => config.set_export_alacritty(enabled),
+            Exporter::Foot => config.set_export_foot(enabled),
+            Exporter::Gtk => config.set_export_gtk(enabled),
+            Exporter::Hyprland => config.set_export_hyprland(enabled),
+        }
+    }
+
+    /// File the exporter writes. Users include/source it from their own config.
+    pub fn output_path(&self) -> PathBuf {
+        let config_dir = dirs::home_dir()
+            .map(|home| home.join(".config"))
+            .unwrap_or_else(|| PathBuf::from("/tmp"));
+        match self {
+            Exporter::Kitty => config_dir.join("kitty").join("alloy-colors.conf"),
+            Exporter::Alacritty => config_dir.join("alacritty").join("alloy-colors.toml"),
+            Exporter::Foot => config_dir.join("foot").join("alloy-colors.ini"),
+            Exporter::Gtk => config_dir.join("gtk-4.0").join("gtk.css"),
+            Exporter::Hyprland => config_dir.join("hypr").join("alloy-colors.conf"),
+        }
+    }
+
+    pub fn export(&self, config: &ColorConfig) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.output_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = match self {
+            Exporter::Kitty => render_kitty(config),
+            Exporter::Alacritty => render_alacritty(config),
+            Exporter::Foot => render_foot(config),
+            Exporter::Hyprland => render_hyprland(config),
+            Exporter::Gtk => {
+                // gtk.css belongs to the user - only replace our own block
+                let existing = fs::read_to_string(&path).unwrap_or_default();
+                replace_managed_block(&existing, &render_gtk(config))
+            }
+        };
+
+        // Skip the write (and the reload signal) when nothing changed
+        if fs::read_to_string(&path).map(|c| c == content).unwrap_or(false) {
+            return Ok(());
+        }
+        fs::write(&path, content)?;
+
+        if *self == Exporter::Kitty {
+            // Kitty re-reads its config on SIGUSR1
+            let _ = Command::new("pkill").args(["-USR1", "-x", "kitty"]).output();
+        }
+
+        Ok(())
+    }
+}
+
+/// Run every enabled exporter. Errors are collected per exporter so one broken
+/// target doesn't stop the others.
+pub fn export_enabled(config: &ColorConfig) -> Vec<(Exporter, String)> {
+    let mut errors = Vec::new();
+    for exporter in ALL_EXPORTERS {
+        if exporter.is_enabled(config) {
+            if let Err(e) = exporter.export(config) {
+                errors.push((*exporter, e.to_string()));
+            }
+        }
+    }
+    errors
+}
+
+fn render_kitty(config: &ColorConfig) -> String {
+    let accent = color::to_hex(&config.accent);
+    format!(
+        "# Generated by Fuse - add `include alloy-colors.conf` to kitty.conf\n\
+         foreground {fg}\n\
+         background {bg}\n\
+         cursor {accent}\n\
+         cursor_text_color {bg}\n\
+         selection_foreground {accent_fg}\n\
+         selection_background {accent}\n\
+         url_color {accent}\n\
+         active_border_color {accent}\n\
+         inactive_border_color {secondary}\n\
+         active_tab_foreground {accent_fg}\n\
+         active_tab_background {accent}\n\
+         inactive_tab_foreground {fg}\n\
+         inactive_tab_background {secondary}\n\
+         color4 {accent}\n\
+         color12 {accent}\n",
+        fg = color::to_hex(&config.text),
+        bg = color::to_hex(&config.background),
+        secondary = color::to_hex(&config.secondary),
+        accent_fg = color::get_contrasting_text_color(&accent),
+        accent = accent,
+    )
+}
+
+fn render_alacritty(config: &ColorConfig) -> String {
+    let accent = color::to_hex(&config.accent);
+    format!(
+        "# Generated by Fuse - add \"~/.config/alacritty/alloy-colors.toml\" to general.import\n\
+         [colors.primary]\n\
+         background = \"{bg}\"\n\
+         foreground = \"{fg}\"\n\
+         \n\
+         [colors.cursor]\n\
+         text = \"{bg}\"\n\
+         cursor = \"{accent}\"\n\
+         \n\
+         [colors.selection]\n\
+         text = \"{accent_fg}\"\n\
+         background = \"{accent}\"\n\
+         \n\
+         [colors.normal]\n\
+         blue = \"{accent}\"\n\
+         \n\
+         [colors.bright]\n\
+         blue = \"{accent}\"\n",
+        fg = color::to_hex(&config.text),
+        bg = color::to_hex(&config.background),
+        accent_fg = color::get_contrasting_text_color(&accent),
+        accent = accent,
+    )
+}
+
+fn render_foot(config: &ColorConfig) -> String {
+    // foot wants bare RRGGBB without '#'
+    let accent = color::strip_hash(&config.accent);
+    format!(
+        "# Generated by Fuse - add `include=~/.config/foot/alloy-colors.ini` to foot.ini\n\
+         [colors]\n\
+         foreground={fg}\n\
+         background={bg}\n\
+         selection-foreground={accent_fg}\n\
+         selection-background={accent}\n\
+         regular4={accent}\n\
+         bright4={accent}\n",
+        fg = color::strip_hash(&config.text),
+        bg = color::strip_hash(&config.background),
+        accent_fg = color::strip_hash(&color::get_contrasting_text_color(&config.accent)),
+        accent = accent,
+    )
+}
+
+fn render_gtk(config: &ColorConfig) -> String {
+    let accent = color::to_hex(&config.accent);
+    format!(
+        "{start}\n\
+         @define-color window_bg_color {bg};\n\
+         @define-color window_fg_color {fg};\n\
+         @define-color headerbar_bg_color {bg};\n\
+         @define-color headerbar_fg_color {fg};\n\
+         @define-color card_bg_color {secondary};\n\
+         @define-color card_fg_color {fg};\n\
+         @define-color popover_bg_color {primary};\n\
+         @define-color popover_fg_color {fg};\n\
+         @define-color view_bg_color {bg};\n\
+         @define-color view_fg_color {fg};\n\
+         @define-color sidebar_bg_color {secondary};\n\
+         @define-color sidebar_fg_color {fg};\n\
+         @define-color accent_bg_color {accent};\n\
+         @define-color accent_fg_color {accent_fg};\n\
+         @define-color accent_color {accent};\n\
+         @define-color error_color {error};\n\
+         @define-color warning_color {warning};\n\
+         @define-color success_color {success};\n\
+         {end}",
+        start = GTK_BLOCK_START,
+        end = GTK_BLOCK_END,
+        bg = color::to_hex(&config.background),
+        fg = color::to_hex(&config.text),
+        primary = color::to_hex(&config.primary),
+        secondary = color::to_hex(&config.secondary),
+        accent_fg = color::get_contrasting_text_color(&accent),
+        accent = accent,
+        error = color::to_hex(&config.status_color("error")),
+        warning = color::to_hex(&config.status_color("warning")),
+        success = color::to_hex(&config.status_color("success")),
+    )
+}
+
+fn render_hyprland(config: &ColorConfig) -> String {
+    format!(
+        "# Generated by Fuse - add `source = ~/.config/hypr/alloy-colors.conf` to hyprland.conf\n\
+         $alloy_background = rgb({bg})\n\
+         $alloy_primary = rgb({primary})\n\
+         $alloy_secondary = rgb({secondary})\n\
+         $alloy_text = rgb({fg})\n\
+         $alloy_accent = rgb({accent})\n\
+         $alloy_error = rgb({error})\n\
+         $alloy_warning = rgb({warning})\n\
+         $alloy_success = rgb({success})\n\
+         $alloy_info = rgb({info})\n\
+         \n\
+         general {{\n\
+         \x20   col.active_border = $alloy_accent\n\
+         \x20   col.inactive_border = $alloy_secondary\n\
+         }}\n",
+        bg = color::strip_hash(&config.background),
+        primary = color::strip_hash(&config.primary),
+        secondary = color::strip_hash(&config.secondary),
+        fg = color::strip_hash(&config.text),
+        accent = color::strip_hash(&config.accent),
+        error = color::strip_hash(&config.status_color("error")),
+        warning = color::strip_hash(&config.status_color("warning")),
+        success = color::strip_hash(&config.status_color("success")),
+        info = color::strip_hash(&config.status_color("info")),
+    )
+}
+
+/// Replace (or append) the Fuse-managed block in `existing`, leaving everything else intact.
+fn replace_managed_block(existing: &str, block: &str) -> String {
+    if let (Some(start), Some(end)) = (existing.find(GTK_BLOCK_START), existing.find(GTK_BLOCK_END)) {
+        if start < end {
+            let end = end + GTK_BLOCK_END.len();
+            return format!("{}{}{}", &existing[..start], block, &existing[end..]);
+        }
+    }
+
+    if existing.trim().is_empty() {
+        format!("{}\n", block)
+    } else {
+        format!("{}\n\n{}\n", existing.trim_end(), block)
+    }
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::PathBuf;
+use dirs;
+use gtk4::gio;
+use gtk4::gio::prelude::*;
+
+const INTERFACE_SCHEMA: &str = "org.gnome.desktop.interface";
+
+fn settings_ini_paths() -> Vec<PathBuf> {
+    let config_dir = dirs::home_dir()
+        .map(|home| home.join(".config"))
+        .unwrap_or_else(|| PathBuf::from("/tmp"));
+    vec![
+        config_dir.join("gtk-3.0").join("settings.ini"),
+        config_dir.join("gtk-4.0").join("settings.ini"),
+    ]
+}
+
+/// Tell other GTK / libadwaita apps (and xdg-desktop-portal) whether we're light or dark.
+///
+/// Writes `gtk-application-prefer-dark-theme` to gtk-3.0 and gtk-4.0 settings.ini and sets
+/// `org.gnome.desktop.interface color-scheme` when that schema is installed.
+/// Unchanged values are left alone, so this is cheap to call on every palette change.
+pub fn publish_color_scheme(dark: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let value = if dark { "true" } else { "false" };
+    for path in settings_ini_paths() {
+        let existing = fs::read_to_string(&path).unwrap_or_default();
+        let updated = set_ini_key(&existing, "Settings", "gtk-application-prefer-dark-theme", value);
+        if updated == existing {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, updated)?;
+    }
+
+    set_gsettings_color_scheme(if dark { "prefer-dark" } else { "default" });
+    Ok(())
+}
+
+/// Set color-scheme through GSettings. Silently does nothing without the GNOME schema
+/// (Settings::new would abort the process on a missing schema).
+fn set_gsettings_color_scheme(scheme: &str) {
+    let Some(schema) = gio::SettingsSchemaSource::default()
+        .and_then(|source| source.lookup(INTERFACE_SCHEMA, true))
+    else {
+        return;
+    };
+    if !schema.has_key("color-scheme") {
+        return;
+    }
+
+    let settings = gio::Settings::new(INTERFACE_SCHEMA);
+    if settings.string("color-scheme") != scheme && settings.set_string("color-scheme", scheme).is_ok() {
+        gio::Settings::sync();
+    }
+}
+
+/// Set `key=value` inside `[section]`, adding the section or key when missing.
+fn set_ini_key(content: &str, section: &str, key: &str, value: &str) -> String {
+    let header = format!("[{}]", section);
+    let mut lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
+
+    let Some(start) = lines.iter().position(|l| l.trim() == header) else {
+        let mut out = content.trim_end().to_string();
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+        out.push_str(&format!("{}\n{}={}\n", header, key, value));
+        return out;
+    };
+
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .map(|i| start + 1 + i)
+        .unwrap_or(lines.len());
+
+    let existing = lines[start + 1..end].iter().position(|l| {
+        l.split_once('=').map(|(k, _)| k.trim() == key).unwrap_or(false)
+    });
+    match existing {
+        Some(i) => lines[start + 1 + i] = format!("{}={}", key, value),
+        None => {
+            // Keep the key next to the other entries, before any blank lines closing the section
+            let mut insert_at = end;
+            while insert_at > start + 1 && lines[insert_at - 1].trim().is_empty() {
+                insert_at -= 1;
+            }
+            lines.insert(insert_at, format!("{}={}", key, value));
+        }
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out
+}
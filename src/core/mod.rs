@@ -1,4 +1,17 @@
 pub mod config;
+pub mod color;
+pub mod presets;
+pub mod theme_schedule;
+pub mod exporters;
+pub mod templates;
+pub mod thumbnails;
+pub mod backgrounds;
+pub mod wallpapers;
+pub mod duplicates;
+pub mod wallpaper_meta;
+pub mod slideshow;
+pub mod gtk_settings;
+pub mod monitors;
 pub mod quickshell;
 pub mod audio;
 pub mod autostart;
@@ -0,0 +1,43 @@
+use serde_json::Value;
+use std::process::Command;
+
+/// A connected output, as reported by the compositor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// Output name, e.g. "DP-1" - the key in monitorWallpapers
+    pub name: String,
+    pub description: String,
+    pub width: i32,
+    pub height: i32,
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Connected outputs from `hyprctl monitors -j`, left to right.
+/// Empty when Hyprland isn't running; callers fall back to GDK's monitor list.
+pub fn list_monitors() -> Vec<Monitor> {
+    let output = match Command::new("hyprctl").args(["monitors", "-j"]).output() {
+        Ok(output) if output.status.success() => output,
+        _ => return Vec::new(),
+    };
+    let Ok(Value::Array(entries)) = serde_json::from_slice::<Value>(&output.stdout) else {
+        return Vec::new();
+    };
+
+    let int = |entry: &Value, key: &str| entry.get(key).and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+    let mut monitors: Vec<Monitor> = entries
+        .iter()
+        .filter_map(|entry| {
+            Some(Monitor {
+                name: entry.get("name")?.as_str()?.to_string(),
+                description: entry.get("description").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                width: int(entry, "width"),
+                height: int(entry, "height"),
+                x: int(entry, "x"),
+                y: int(entry, "y"),
+            })
+        })
+        .collect();
+    monitors.sort_by_key(|m| (m.x, m.y));
+    monitors
+}
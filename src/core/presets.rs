@@ -0,0 +1,90 @@
+use crate::core::color;
+use crate::core::config::ColorConfig;
+
+// 8 new color presets, each with light and dark variants
+// Format: (name, theme, background, primary, secondary, text, accent)
+type ColorPreset = (&'static str, &'static str, &'static str, &'static str, &'static str, &'static str, &'static str);
+
+pub const COLOR_PRESETS: &[ColorPreset] = &[
+    // Preset 1: Midnight (Mono)
+    ("Midnight (Mono)", "light", "#ffffff", "#f5f5f5", "#e5e5e5", "#000000", "#333333"),
+    ("Midnight (Mono)", "dark", "#000000", "#121212", "#080808", "#ffffff", "#c0c0c0"),
+    // Preset 2: Gruvbox
+    ("Gruvbox", "light", "#fbf1c7", "#f2e5bc", "#ebdbb2", "#3c3836", "#af3a03"),
+    ("Gruvbox", "dark", "#282828", "#32302f", "#1d2021", "#ebdbb2", "#d65d0e"),
+    // Preset 3: Catppuccin
+    ("Catppuccin", "light", "#eff1f5", "#e6e9ef", "#ccd0da", "#4c4f69", "#8839ef"),
+    ("Catppuccin", "dark", "#24273a", "#363a4f", "#494d64", "#cad3f5", "#c6a0f6"),
+    // Preset 4: Nord
+    ("Nord", "light", "#eceff4", "#e5e9f0", "#d8dee9", "#2e3440", "#5e81ac"),
+    ("Nord", "dark", "#2e3440", "#3b4252", "#434c5e", "#eceff4", "#88c0d0"),
+    // Preset 5: Dracula
+    ("Dracula", "light", "#f8f8f2", "#e2e2e2", "#dcdcdc", "#282a36", "#6272a4"),
+    ("Dracula", "dark", "#282a36", "#44475a", "#6272a4", "#f8f8f2", "#bd93f9"),
+];
+
+// Status colors for each preset variant, tuned for contrast against its background
+// Format: (name, theme, error, warning, success, info)
+pub const STATUS_PRESETS: &[(&str, &str, &str, &str, &str, &str)] = &[
+    ("Midnight (Mono)", "light", "#c01c28", "#b35900", "#1e7d32", "#1c71d8"),
+    ("Midnight (Mono)", "dark", "#ff6b6b", "#ffb454", "#6bd968", "#62a0ea"),
+    ("Gruvbox", "light", "#9d0006", "#b57614", "#79740e", "#076678"),
+    ("Gruvbox", "dark", "#fb4934", "#fabd2f", "#b8bb26", "#83a598"),
+    ("Catppuccin", "light", "#d20f39", "#df8e1d", "#40a02b", "#1e66f5"),
+    ("Catppuccin", "dark", "#ed8796", "#eed49f", "#a6da95", "#8aadf4"),
+    ("Nord", "light", "#bf616a", "#c77d3a", "#6f8f4e", "#5e81ac"),
+    ("Nord", "dark", "#bf616a", "#ebcb8b", "#a3be8c", "#81a1c1"),
+    ("Dracula", "light", "#cb3a2a", "#a67f00", "#2d8a3e", "#6272a4"),
+    ("Dracula", "dark", "#ff5555", "#f1fa8c", "#50fa7b", "#8be9fd"),
+];
+
+
+/// Copy the status colors of a preset variant into `cfg` (no-op for unknown presets).
+pub fn apply_status_colors(cfg: &mut ColorConfig, name: &str, theme: &str) {
+    if let Some(status) = STATUS_PRESETS.iter().find(|p| p.0 == name && p.1 == theme) {
+        cfg.set_status_colors(status.2, status.3, status.4, status.5);
+    }
+}
+
+/// Theme variant ("light" / "dark") of the colors currently in `cfg`, judged by the background.
+pub fn current_variant(cfg: &ColorConfig) -> &'static str {
+    if color::is_light(&cfg.background) {
+        "light"
+    } else {
+        "dark"
+    }
+}
+
+/// Switch `cfg` to the `theme` variant of its current preset.
+/// Returns false (leaving `cfg` alone) when that preset has no such variant, e.g. for a custom palette.
+pub fn apply_variant(cfg: &mut ColorConfig, theme: &str) -> bool {
+    let preset_name = cfg.color_preset.clone().unwrap_or_default();
+    match COLOR_PRESETS.iter().find(|p| p.0 == preset_name && p.1 == theme) {
+        Some(preset) => {
+            apply_preset(cfg, preset);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Like `apply_variant`, but falls back to the first preset with that variant.
+/// Only for an explicit choice by the user - it replaces a custom palette.
+pub fn apply_any_variant(cfg: &mut ColorConfig, theme: &str) -> bool {
+    if apply_variant(cfg, theme) {
+        return true;
+    }
+    match COLOR_PRESETS.iter().find(|p| p.1 == theme) {
+        Some(preset) => {
+            apply_preset(cfg, preset);
+            true
+        }
+        None => false,
+    }
+}
+
+fn apply_preset(cfg: &mut ColorConfig, preset: &ColorPreset) {
+    cfg.update_colors(preset.2, preset.3, preset.4, preset.5, preset.6);
+    apply_status_colors(cfg, preset.0, preset.1);
+    cfg.set_preset(preset.0);
+}
@@ -1,23 +1,56 @@
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use dirs;
 use crate::core::config::ColorConfig;
+use crate::core::wallpapers::WallpaperKind;
 
 const WALLPAPER_PATH_FILE: &str = "/tmp/quickshell_wallpaper_path";
+/// "image", "animated" or "video" - tells Quickshell which renderer the path needs
+const WALLPAPER_TYPE_FILE: &str = "/tmp/quickshell_wallpaper_type";
 const COLOR_CHANGE_FILE: &str = "/tmp/quickshell_color_change";
 
+/// Show `wallpaper_path` and record it in colors.json. Finding out whether a GIF or WebP is
+/// animated reads the file, so call this off the main thread.
 pub fn set_wallpaper(wallpaper_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    // Type first, so it is already there when quickshell sees the new path
+    let kind = WallpaperKind::of(Path::new(wallpaper_path));
+    fs::write(WALLPAPER_TYPE_FILE, kind.key())?;
     // Write wallpaper path to file that quickshell monitors
     fs::write(WALLPAPER_PATH_FILE, wallpaper_path)?;
     
     // Also update colors.json
     let mut config = ColorConfig::load();
     config.set_wallpaper(wallpaper_path);
+    config.set_wallpaper_type(kind.key());
     config.save()?;
     
     Ok(())
 }
 
+/// Show `wallpaper_path` on one output only (None: back to the main wallpaper there).
+/// Like `set_wallpaper`, call this off the main thread.
+pub fn set_monitor_wallpaper(output: &str, wallpaper_path: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = ColorConfig::load();
+    config.set_monitor_wallpaper(output, wallpaper_path);
+    config.save()?;
+
+    // Per-output counterparts of WALLPAPER_TYPE_FILE and WALLPAPER_PATH_FILE. Only outputs with
+    // a wallpaper of their own get them; the rest follow the main files.
+    let type_file = format!("{}_{}", WALLPAPER_TYPE_FILE, output);
+    let path_file = format!("{}_{}", WALLPAPER_PATH_FILE, output);
+    match config.monitor_wallpapers.as_ref().and_then(|w| w.get(output)) {
+        Some(path) => {
+            fs::write(&type_file, WallpaperKind::of(Path::new(&path)).key())?;
+            fs::write(&path_file, path)?;
+        }
+        None => {
+            let _ = fs::remove_file(&type_file);
+            let _ = fs::remove_file(&path_file);
+        }
+    }
+    notify_color_change()
+}
+
 pub fn notify_color_change() -> Result<(), Box<dyn std::error::Error>> {
     // Write path to colors.json so Quickshell reads the exact file we just saved.
     // Format: "PATH\n" – Quickshell uses first line as path, then loads that file.
@@ -0,0 +1,271 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::core::backgrounds;
+use crate::core::config::{expand_home, ColorConfig};
+use crate::core::quickshell;
+use crate::core::wallpaper_meta::WallpaperMeta;
+use crate::core::wallpapers;
+
+pub const DEFAULT_INTERVAL_MINUTES: u32 = 30;
+
+/// Commands the running slideshow picks up from its command file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlideshowCommand {
+    Next,
+    Previous,
+    Pause,
+    Resume,
+    Stop,
+}
+
+impl SlideshowCommand {
+    pub fn key(&self) -> &'static str {
+        match self {
+            SlideshowCommand::Next => "next",
+            SlideshowCommand::Previous => "previous",
+            SlideshowCommand::Pause => "pause",
+            SlideshowCommand::Resume => "resume",
+            SlideshowCommand::Stop => "stop",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key.trim() {
+            "next" => Some(SlideshowCommand::Next),
+            "previous" => Some(SlideshowCommand::Previous),
+            "pause" => Some(SlideshowCommand::Pause),
+            "resume" => Some(SlideshowCommand::Resume),
+            "stop" => Some(SlideshowCommand::Stop),
+            _ => None,
+        }
+    }
+}
+
+fn runtime_file(name: &str) -> PathBuf {
+    dirs::runtime_dir().unwrap_or_else(std::env::temp_dir).join(name)
+}
+
+fn pid_file() -> PathBuf {
+    runtime_file("fuse-slideshow.pid")
+}
+
+fn command_file() -> PathBuf {
+    runtime_file("fuse-slideshow.cmd")
+}
+
+fn paused_file() -> PathBuf {
+    runtime_file("fuse-slideshow.paused")
+}
+
+/// PID of the running slideshow process, if there is one.
+pub fn running_pid() -> Option<u32> {
+    let pid: u32 = fs::read_to_string(pid_file()).ok()?.trim().parse().ok()?;
+    // A stale PID file survives crashes - make sure the process is still a fuse slideshow
+    let cmdline = fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+    if String::from_utf8_lossy(&cmdline).contains("--slideshow") {
+        Some(pid)
+    } else {
+        None
+    }
+}
+
+pub fn is_paused() -> bool {
+    running_pid().is_some() && paused_file().exists()
+}
+
+/// Ask the running slideshow to do something. Commands are handled within a second.
+pub fn send_command(command: SlideshowCommand) -> std::io::Result<()> {
+    if running_pid().is_none() {
+        return Err(std::io::Error::new(std::io::ErrorKind::NotFound, "slideshow is not running"));
+    }
+    fs::write(command_file(), command.key())
+}
+
+/// Start the slideshow as a detached `fuse --slideshow` process, unless it's already running.
+pub fn spawn_daemon() -> std::io::Result<()> {
+    if running_pid().is_some() {
+        return Ok(());
+    }
+    Command::new(std::env::current_exe()?).arg("--slideshow").spawn()?;
+    Ok(())
+}
+
+fn take_command() -> Option<SlideshowCommand> {
+    let path = command_file();
+    let content = fs::read_to_string(&path).ok()?;
+    let _ = fs::remove_file(&path);
+    SlideshowCommand::from_key(&content)
+}
+
+/// Marks a collection name (rather than a folder) in `slideshowSource`
+pub const COLLECTION_PREFIX: &str = "collection:";
+
+/// Human-readable slideshow source for the settings row.
+pub fn source_label(config: &ColorConfig) -> String {
+    match config.slideshow_source.as_deref() {
+        None => "All wallpaper folders".to_string(),
+        Some(source) => match source.strip_prefix(COLLECTION_PREFIX) {
+            Some(name) => format!("Collection: {}", name),
+            None => source.to_string(),
+        },
+    }
+}
+
+/// Wallpapers the slideshow picks from: the chosen source folder or collection,
+/// or every wallpaper folder.
+pub fn playlist(config: &ColorConfig) -> Vec<PathBuf> {
+    match config.slideshow_source.as_deref() {
+        Some(source) => match source.strip_prefix(COLLECTION_PREFIX) {
+            Some(name) => WallpaperMeta::load()
+                .collections
+                .get(name)
+                .map(|members| members.iter().map(PathBuf::from).filter(|p| p.is_file()).collect())
+                .unwrap_or_default(),
+            None => wallpapers::find_wallpapers_in(&[expand_home(source)], config),
+        },
+        None => wallpapers::find_wallpapers(config),
+    }
+}
+
+/// xorshift64 - good enough to shuffle wallpapers without pulling in a crate
+struct Rng(u64);
+
+impl Rng {
+    fn new() -> Self {
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(1);
+        Rng(seed | 1)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % n as u64) as usize
+    }
+}
+
+struct Slideshow {
+    rng: Rng,
+    /// Shuffled wallpapers still to show this round
+    bag: Vec<PathBuf>,
+    /// Wallpapers shown so far, for "previous"
+    history: Vec<String>,
+}
+
+impl Slideshow {
+    fn next(&mut self, config: &ColorConfig) -> Option<String> {
+        let list = playlist(config);
+        if list.is_empty() {
+            return None;
+        }
+        let current = config.last_wallpaper.clone().unwrap_or_default();
+
+        let next = if config.slideshow_order.as_deref() == Some("ordered") {
+            let position = list.iter().position(|p| p.to_string_lossy() == current);
+            let index = position.map(|i| (i + 1) % list.len()).unwrap_or(0);
+            list[index].clone()
+        } else {
+            // Shuffle bag: every wallpaper once per round, in random order
+            self.bag.retain(|p| list.contains(p));
+            if self.bag.is_empty() {
+                self.bag = list.clone();
+                for i in (1..self.bag.len()).rev() {
+                    let j = self.rng.below(i + 1);
+                    self.bag.swap(i, j);
+                }
+                // Don't start the new round with what's already on screen
+                if self.bag.len() > 1 && self.bag.last().map(|p| p.to_string_lossy() == current).unwrap_or(false) {
+                    let last = self.bag.len() - 1;
+                    self.bag.swap(0, last);
+                }
+            }
+            self.bag.pop()?
+        };
+
+        if !current.is_empty() {
+            self.history.push(current);
+        }
+        Some(next.to_string_lossy().to_string())
+    }
+
+    fn previous(&mut self) -> Option<String> {
+        self.history.pop()
+    }
+}
+
+fn show(wallpaper: &str) {
+    if let Err(e) = quickshell::set_wallpaper(wallpaper) {
+        eprintln!("Failed to set wallpaper {}: {}", wallpaper, e);
+        return;
+    }
+    // The settings app may not be running to do this for us
+    backgrounds::update_lockscreen_background();
+}
+
+/// The slideshow loop behind `fuse --slideshow`. Runs until the slideshow is disabled
+/// in colors.json or a stop command arrives.
+pub fn run_daemon() -> i32 {
+    if let Some(pid) = running_pid() {
+        eprintln!("Slideshow already running (pid {})", pid);
+        return 1;
+    }
+    if let Err(e) = fs::write(pid_file(), std::process::id().to_string()) {
+        eprintln!("Failed to write {}: {}", pid_file().display(), e);
+        return 1;
+    }
+    let _ = fs::remove_file(command_file());
+    let _ = fs::remove_file(paused_file());
+
+    let mut slideshow = Slideshow { rng: Rng::new(), bag: Vec::new(), history: Vec::new() };
+    let mut elapsed = 0u32;
+
+    loop {
+        std::thread::sleep(Duration::from_secs(1));
+
+        let config = ColorConfig::load();
+        if !config.slideshow_enabled.unwrap_or(false) {
+            break;
+        }
+
+        let paused = paused_file().exists();
+        match take_command() {
+            Some(SlideshowCommand::Stop) => break,
+            Some(SlideshowCommand::Pause) => {
+                let _ = fs::write(paused_file(), "");
+            }
+            Some(SlideshowCommand::Resume) => {
+                let _ = fs::remove_file(paused_file());
+            }
+            Some(SlideshowCommand::Next) => {
+                if let Some(wallpaper) = slideshow.next(&config) {
+                    show(&wallpaper);
+                }
+                elapsed = 0;
+            }
+            Some(SlideshowCommand::Previous) => {
+                if let Some(wallpaper) = slideshow.previous() {
+                    show(&wallpaper);
+                }
+                elapsed = 0;
+            }
+            None if !paused => {
+                elapsed += 1;
+                let interval = config.slideshow_interval.unwrap_or(DEFAULT_INTERVAL_MINUTES).max(1) * 60;
+                if elapsed >= interval {
+                    if let Some(wallpaper) = slideshow.next(&config) {
+                        show(&wallpaper);
+                    }
+                    elapsed = 0;
+                }
+            }
+            None => {}
+        }
+    }
+
+    let _ = fs::remove_file(pid_file());
+    let _ = fs::remove_file(paused_file());
+    0
+}
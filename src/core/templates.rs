@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+use dirs;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+use crate::core::color;
+use crate::core::config::{expand_home, ColorConfig};
+
+const MANIFEST_FILE: &str = "templates.json";
+
+/// One entry of ~/.config/alloy/templates/templates.json
+///
+/// ```json
+/// [{ "template": "rofi.rasi", "output": "~/.config/rofi/colors.rasi", "postHook": "pkill -USR1 waybar" }]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateEntry {
+    pub template: String,
+    pub output: String,
+    #[serde(rename = "postHook", default, skip_serializing_if = "Option::is_none")]
+    pub post_hook: Option<String>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+pub fn get_templates_dir() -> PathBuf {
+    if let Some(home) = dirs::home_dir() {
+        home.join(".config").join("alloy").join("templates")
+    } else {
+        PathBuf::from("/tmp/alloy/templates")
+    }
+}
+
+pub fn get_manifest_path() -> PathBuf {
+    get_templates_dir().join(MANIFEST_FILE)
+}
+
+pub fn load_manifest() -> Vec<TemplateEntry> {
+    fs::read_to_string(get_manifest_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Create the templates directory with an empty manifest so users know where to start.
+pub fn ensure_templates_dir() -> std::io::Result<PathBuf> {
+    let dir = get_templates_dir();
+    fs::create_dir_all(&dir)?;
+    let manifest = dir.join(MANIFEST_FILE);
+    if !manifest.exists() {
+        fs::write(&manifest, "[]\n")?;
+    }
+    Ok(dir)
+}
+
+/// Render every enabled template in the manifest. Returns (template, error) for failures.
+pub fn render_all(config: &ColorConfig) -> Vec<(String, String)> {
+    let mut errors = Vec::new();
+    for entry in load_manifest().iter().filter(|e| e.enabled) {
+        if let Err(e) = render_entry(entry, config) {
+            errors.push((entry.template.clone(), e.to_string()));
+        }
+    }
+    errors
+}
+
+pub fn render_entry(entry: &TemplateEntry, config: &ColorConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let template_path = resolve_template_path(&entry.template);
+    let source = fs::read_to_string(&template_path)?;
+    let rendered = render(&source, config);
+
+    let output = expand_home(&entry.output);
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // Don't touch unchanged files so watchers (waybar, dunst...) aren't reloaded for nothing
+    if fs::read_to_string(&output).map(|c| c == rendered).unwrap_or(false) {
+        return Ok(());
+    }
+    fs::write(&output, rendered)?;
+
+    if let Some(hook) = entry.post_hook.clone().filter(|h| !h.trim().is_empty()) {
+        // Run off the main thread - hooks may restart whole programs
+        std::thread::spawn(move || {
+            let _ = Command::new("sh").arg("-c").arg(&hook).output();
+        });
+    }
+
+    Ok(())
+}
+
+fn resolve_template_path(template: &str) -> PathBuf {
+    let path = expand_home(template);
+    if path.is_absolute() {
+        path
+    } else {
+        get_templates_dir().join(Path::new(template))
+    }
+}
+
+/// Replace `{{role}}`, `{{role.rgb}}`, `{{role.lighten(10)}}`... with values from `config`.
+///
+/// Roles: background, primary, secondary, text, accent, accent_fg,
+/// accent_50 … accent_900, accent_hover, accent_pressed, error, warning, success, info.
+/// Filters (chainable, applied left to right): hex, strip, rgb, lighten(n), darken(n).
+/// Unknown roles or filters are left untouched.
+pub fn render(source: &str, config: &ColorConfig) -> String {
+    static PLACEHOLDER_RE: OnceLock<Regex> = OnceLock::new();
+    static FILTER_RE: OnceLock<Regex> = OnceLock::new();
+    let re = PLACEHOLDER_RE.get_or_init(|| {
+        Regex::new(r"\{\{\s*([a-z_0-9]+)((?:\.[a-z]+(?:\(\s*-?[0-9.]+\s*\))?)*)\s*\}\}").unwrap()
+    });
+    let filter_re = FILTER_RE.get_or_init(|| Regex::new(r"\.([a-z]+)(?:\(\s*(-?[0-9.]+)\s*\))?").unwrap());
+
+    re.replace_all(source, |caps: &Captures| {
+        let original = caps[0].to_string();
+        let Some(base) = role_color(&caps[1], config) else {
+            return original;
+        };
+
+        let mut value = color::to_hex(&base);
+        let mut format = "hex";
+        for filter in filter_re.captures_iter(&caps[2]) {
+            let arg = filter.get(2).and_then(|a| a.as_str().parse::<f64>().ok());
+            match (&filter[1], arg) {
+                ("lighten", Some(amount)) => value = color::lighten(&value, amount),
+                ("darken", Some(amount)) => value = color::darken(&value, amount),
+                ("hex", None) => format = "hex",
+                ("strip", None) => format = "strip",
+                ("rgb", None) => format = "rgb",
+                _ => return original,
+            }
+        }
+
+        match format {
+            "strip" => color::strip_hash(&value),
+            "rgb" => match color::parse_hex(&value) {
+                Some((r, g, b)) => format!("{}, {}, {}", r, g, b),
+                None => original,
+            },
+            _ => value,
+        }
+    })
+    .into_owned()
+}
+
+fn role_color(role: &str, config: &ColorConfig) -> Option<String> {
+    match role {
+        "background" => Some(config.background.clone()),
+        "primary" => Some(config.primary.clone()),
+        "secondary" => Some(config.secondary.clone()),
+        "text" => Some(config.text.clone()),
+        "accent" => Some(config.accent.clone()),
+        "accent_fg" => Some(color::get_contrasting_text_color(&config.accent)),
+        "error" | "warning" | "success" | "info" => Some(config.status_color(role)),
+        _ if role.starts_with("accent_") => {
+            let key = &role["accent_".len()..];
+            config.accent_palette.as_ref().and_then(|p| p.get(key).cloned())
+        }
+        _ => None,
+    }
+}
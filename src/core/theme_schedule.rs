@@ -0,0 +1,117 @@
+use gtk4::glib;
+
+use crate::core::config::ColorConfig;
+use crate::core::presets;
+
+const DEFAULT_LIGHT_MINUTES: u32 = 7 * 60;
+const DEFAULT_DARK_MINUTES: u32 = 19 * 60;
+
+/// Sunrise/sunset for one day, in local minutes after midnight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SunTimes {
+    Normal { sunrise: u32, sunset: u32 },
+    /// Polar day - the sun never sets
+    AlwaysUp,
+    /// Polar night - the sun never rises
+    AlwaysDown,
+}
+
+/// Parse "HH:MM" into minutes after midnight.
+pub fn parse_time(value: &str) -> Option<u32> {
+    let (h, m) = value.trim().split_once(':')?;
+    let (h, m) = (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?);
+    if h < 24 && m < 60 {
+        Some(h * 60 + m)
+    } else {
+        None
+    }
+}
+
+pub fn format_time(minutes: u32) -> String {
+    format!("{:02}:{:02}", (minutes / 60) % 24, minutes % 60)
+}
+
+/// NOAA approximation of sunrise/sunset (good to a couple of minutes), computed offline.
+pub fn sun_times(day_of_year: u32, latitude: f64, longitude: f64, utc_offset_minutes: i32) -> SunTimes {
+    let gamma = 2.0 * std::f64::consts::PI / 365.0 * (day_of_year as f64 - 1.0);
+    let eqtime = 229.18
+        * (0.000075 + 0.001868 * gamma.cos() - 0.032077 * gamma.sin()
+            - 0.014615 * (2.0 * gamma).cos() - 0.040849 * (2.0 * gamma).sin());
+    let decl = 0.006918 - 0.399912 * gamma.cos() + 0.070257 * gamma.sin()
+        - 0.006758 * (2.0 * gamma).cos() + 0.000907 * (2.0 * gamma).sin()
+        - 0.002697 * (3.0 * gamma).cos() + 0.00148 * (3.0 * gamma).sin();
+
+    let lat = latitude.to_radians();
+    // 90.833° accounts for refraction and the size of the solar disc
+    let cos_ha = 90.833_f64.to_radians().cos() / (lat.cos() * decl.cos()) - lat.tan() * decl.tan();
+    if cos_ha > 1.0 {
+        return SunTimes::AlwaysDown;
+    }
+    if cos_ha < -1.0 {
+        return SunTimes::AlwaysUp;
+    }
+
+    let ha = cos_ha.acos().to_degrees();
+    let to_local = |utc_minutes: f64| (utc_minutes.round() as i32 + utc_offset_minutes).rem_euclid(24 * 60) as u32;
+    SunTimes::Normal {
+        sunrise: to_local(720.0 - 4.0 * (longitude + ha) - eqtime),
+        sunset: to_local(720.0 - 4.0 * (longitude - ha) - eqtime),
+    }
+}
+
+/// Whether `now` falls between `light_start` and `dark_start` (handles ranges crossing midnight).
+fn in_light_period(now: u32, light_start: u32, dark_start: u32) -> bool {
+    if light_start <= dark_start {
+        now >= light_start && now < dark_start
+    } else {
+        now >= light_start || now < dark_start
+    }
+}
+
+/// Variant the schedule asks for at the given local time, or None when automatic mode is off.
+pub fn desired_variant(config: &ColorConfig, minute_of_day: u32, day_of_year: u32, utc_offset_minutes: i32) -> Option<&'static str> {
+    if config.theme_mode.as_deref() != Some("auto") {
+        return None;
+    }
+
+    let light = match (config.theme_schedule.as_deref(), config.latitude, config.longitude) {
+        (Some("sun"), Some(lat), Some(lon)) => match sun_times(day_of_year, lat, lon, utc_offset_minutes) {
+            SunTimes::Normal { sunrise, sunset } => in_light_period(minute_of_day, sunrise, sunset),
+            SunTimes::AlwaysUp => true,
+            SunTimes::AlwaysDown => false,
+        },
+        _ => {
+            let light_start = config.light_time.as_deref().and_then(parse_time).unwrap_or(DEFAULT_LIGHT_MINUTES);
+            let dark_start = config.dark_time.as_deref().and_then(parse_time).unwrap_or(DEFAULT_DARK_MINUTES);
+            in_light_period(minute_of_day, light_start, dark_start)
+        }
+    };
+
+    Some(if light { "light" } else { "dark" })
+}
+
+/// desired_variant() for the current local time.
+pub fn desired_variant_now(config: &ColorConfig) -> Option<&'static str> {
+    let now = glib::DateTime::now_local().ok()?;
+    desired_variant(
+        config,
+        (now.hour() * 60 + now.minute()) as u32,
+        now.day_of_year() as u32,
+        now.utc_offset().as_minutes() as i32,
+    )
+}
+
+/// Today's sunrise/sunset for the configured location, if one is set.
+pub fn sun_times_today(config: &ColorConfig) -> Option<SunTimes> {
+    let (lat, lon) = (config.latitude?, config.longitude?);
+    let now = glib::DateTime::now_local().ok()?;
+    Some(sun_times(now.day_of_year() as u32, lat, lon, now.utc_offset().as_minutes() as i32))
+}
+
+/// Switch `cfg` to the scheduled variant. Returns true when colors changed and need saving.
+pub fn apply_schedule(cfg: &mut ColorConfig) -> bool {
+    match desired_variant_now(cfg) {
+        Some(variant) if variant != presets::current_variant(cfg) => presets::apply_variant(cfg, variant),
+        _ => false,
+    }
+}
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::core::color;
+use crate::core::wallpapers;
+
+/// Longest edge of a cached thumbnail - enough for a 3-column tile on a HiDPI screen
+const THUMBNAIL_SIZE: u32 = 512;
+
+/// $XDG_CACHE_HOME/alloy/thumbnails
+pub fn cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(|| PathBuf::from("/tmp"))
+        .join("alloy")
+        .join("thumbnails")
+}
+
+/// 64-bit FNV-1a; stable across builds, unlike std's DefaultHasher.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Stable key for a file's current contents: covers path, mtime and size,
+/// so an edited or replaced image gets a new key.
+pub fn file_key(path: &Path) -> Option<u64> {
+    let metadata = fs::metadata(path).ok()?;
+    let mtime = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let key = format!("{}\n{}\n{}", path.to_string_lossy(), mtime, metadata.len());
+    Some(fnv1a(key.as_bytes()))
+}
+
+/// Where the thumbnail for `path` lives; a fresh one is made whenever the file changes.
+pub fn thumbnail_path(path: &Path) -> Option<PathBuf> {
+    Some(cache_dir().join(format!("{:016x}.jpg", file_key(path)?)))
+}
+
+/// Size and dominant color of a wallpaper, cached next to its thumbnail.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ImageInfo {
+    pub width: u32,
+    pub height: u32,
+    /// Dominant hue in degrees; None for mostly gray / black / white images
+    pub hue: Option<f64>,
+    /// Perceptual hashes for duplicate detection (missing in caches written before them)
+    #[serde(default)]
+    pub ahash: Option<u64>,
+    #[serde(default)]
+    pub dhash: Option<u64>,
+    /// Standard deviation of the 8x8 luma grid the hashes come from; near 0 for flat images
+    #[serde(default)]
+    pub luma_spread: Option<f64>,
+}
+
+fn info_path(thumb: &Path) -> PathBuf {
+    thumb.with_extension("json")
+}
+
+/// The cached thumbnail, if one was already generated.
+pub fn cached_thumbnail(path: &Path) -> Option<PathBuf> {
+    thumbnail_path(path).filter(|thumb| thumb.is_file())
+}
+
+/// Decode `path`, scale it down and store it in the cache. Slow - call off the main thread.
+pub fn generate_thumbnail(path: &Path) -> Result<PathBuf, Box<dyn std::error::Error + Send + Sync>> {
+    let thumb = thumbnail_path(path).ok_or("wallpaper not readable")?;
+    if thumb.is_file() {
+        return Ok(thumb);
+    }
+
+    let full = decode_still(path)?;
+    let (width, height) = (full.width(), full.height());
+    let image = full.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE).to_rgb8();
+    drop(full);
+    fs::create_dir_all(cache_dir())?;
+
+    // The image is decoded anyway - record what search needs while we have it
+    let (ahash, dhash, spread) = perceptual_hashes(&image);
+    let info = ImageInfo {
+        width,
+        height,
+        hue: dominant_hue(&image),
+        ahash: Some(ahash),
+        dhash: Some(dhash),
+        luma_spread: Some(spread),
+    };
+    fs::write(info_path(&thumb), serde_json::to_string(&info)?)?;
+
+    // Write under a temporary name so a half-written file is never picked up as cached
+    let tmp = thumb.with_extension("jpg.part");
+    image::DynamicImage::ImageRgb8(image).save_with_format(&tmp, image::ImageFormat::Jpeg)?;
+    fs::rename(&tmp, &thumb)?;
+    Ok(thumb)
+}
+
+/// A still picture of `path`: the image itself, the first frame of an animation,
+/// or for videos the first frame as extracted by ffmpeg.
+pub fn decode_still(path: &Path) -> Result<image::DynamicImage, Box<dyn std::error::Error + Send + Sync>> {
+    if !wallpapers::is_video_file(path) {
+        return Ok(image::open(path)?);
+    }
+    let output = std::process::Command::new("ffmpeg")
+        .args(["-v", "error", "-i"])
+        .arg(path)
+        .args(["-frames:v", "1", "-f", "image2pipe", "-vcodec", "png", "-"])
+        .output()?;
+    if !output.status.success() || output.stdout.is_empty() {
+        return Err(format!("ffmpeg could not read {}", path.display()).into());
+    }
+    Ok(image::load_from_memory_with_format(&output.stdout, image::ImageFormat::Png)?)
+}
+
+/// Size and dominant hue of `path`, from the cache or by thumbnailing it. Slow on a cache miss.
+pub fn image_info(path: &Path) -> Option<ImageInfo> {
+    let thumb = generate_thumbnail(path).ok()?;
+    let cached = fs::read_to_string(info_path(&thumb))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok());
+    if let Some(info) = cached.filter(|i: &ImageInfo| i.ahash.is_some() && i.dhash.is_some() && i.luma_spread.is_some()) {
+        return Some(info);
+    }
+
+    // Thumbnail from before (all of) this was recorded: fill it in from the thumbnail
+    let (width, height) = if wallpapers::is_video_file(path) {
+        let frame = decode_still(path).ok()?;
+        (frame.width(), frame.height())
+    } else {
+        image::image_dimensions(path).ok()?
+    };
+    let thumbnail = image::open(&thumb).ok()?.to_rgb8();
+    let (ahash, dhash, spread) = perceptual_hashes(&thumbnail);
+    let info = ImageInfo {
+        width,
+        height,
+        hue: dominant_hue(&thumbnail),
+        ahash: Some(ahash),
+        dhash: Some(dhash),
+        luma_spread: Some(spread),
+    };
+    let _ = fs::write(info_path(&thumb), serde_json::to_string(&info).unwrap_or_default());
+    Some(info)
+}
+
+/// Most common hue among reasonably colorful pixels, weighted by saturation.
+fn dominant_hue(image: &image::RgbImage) -> Option<f64> {
+    const BINS: usize = 36;
+    let mut bins = [0.0f64; BINS];
+    let mut colorful = 0usize;
+    let total = (image.width() * image.height()).max(1) as usize;
+
+    for pixel in image.pixels() {
+        let (h, s, l) = color::rgb_to_hsl(pixel[0], pixel[1], pixel[2]);
+        if s < 0.2 || !(0.1..=0.9).contains(&l) {
+            continue;
+        }
+        colorful += 1;
+        bins[(h / (360.0 / BINS as f64)) as usize % BINS] += s;
+    }
+
+    // Less than a tenth of the picture has any color: call it neutral
+    if colorful * 10 < total {
+        return None;
+    }
+    let (bin, _) = bins.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))?;
+    Some((bin as f64 + 0.5) * 360.0 / BINS as f64)
+}
+
+/// aHash (8x8 brighter-than-mean bits) and dHash (9x8 brighter-than-right-neighbour bits).
+/// Both survive rescaling and recompression, so copies of one image at other sizes match.
+/// Also returns the luma standard deviation of the 8x8 grid.
+fn perceptual_hashes(image: &image::RgbImage) -> (u64, u64, f64) {
+    let gray = image::DynamicImage::ImageRgb8(image.clone()).to_luma8();
+
+    let small = image::imageops::resize(&gray, 8, 8, image::imageops::FilterType::Triangle);
+    let mean = small.pixels().map(|p| p[0] as u32).sum::<u32>() / 64;
+    let variance = small.pixels().map(|p| (p[0] as f64 - mean as f64).powi(2)).sum::<f64>() / 64.0;
+    let ahash = small
+        .pixels()
+        .enumerate()
+        .fold(0u64, |hash, (i, p)| if p[0] as u32 > mean { hash | (1 << i) } else { hash });
+
+    let wide = image::imageops::resize(&gray, 9, 8, image::imageops::FilterType::Triangle);
+    let mut dhash = 0u64;
+    for y in 0..8 {
+        for x in 0..8 {
+            if wide.get_pixel(x, y)[0] > wide.get_pixel(x + 1, y)[0] {
+                dhash |= 1 << (y * 8 + x);
+            }
+        }
+    }
+
+    (ahash, dhash, variance.sqrt())
+}
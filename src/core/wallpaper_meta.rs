@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Favorites, tags and collections, kept in ~/.config/alloy/wallpapers.json.
+/// Wallpapers are keyed by their full path.
+///
+/// ```json
+/// { "favorites": ["/home/me/Pictures/Wallpapers/a.jpg"],
+///   "tags": { "/home/me/Pictures/Wallpapers/a.jpg": ["blue", "calm"] },
+///   "collections": { "Work": ["/home/me/Pictures/Wallpapers/a.jpg"] } }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WallpaperMeta {
+    #[serde(default)]
+    pub favorites: BTreeSet<String>,
+    #[serde(default)]
+    pub tags: BTreeMap<String, BTreeSet<String>>,
+    #[serde(default)]
+    pub collections: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// What the wallpaper grid shows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum WallpaperFilter {
+    #[default]
+    All,
+    Favorites,
+    Tag(String),
+    Collection(String),
+}
+
+impl WallpaperMeta {
+    pub fn path() -> PathBuf {
+        if let Some(home) = dirs::home_dir() {
+            home.join(".config").join("alloy").join("wallpapers.json")
+        } else {
+            PathBuf::from("/tmp/alloy/wallpapers.json")
+        }
+    }
+
+    pub fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = Self::path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write a temp file and rename it over, so a killed process can't leave a truncated file
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, serde_json::to_string_pretty(self)?)?;
+        if let Ok(file) = fs::OpenOptions::new().write(true).open(&temp_path) {
+            file.sync_all()?;
+        }
+        fs::rename(&temp_path, &path)?;
+        Ok(())
+    }
+
+    pub fn is_favorite(&self, wallpaper: &str) -> bool {
+        self.favorites.contains(wallpaper)
+    }
+
+    pub fn set_favorite(&mut self, wallpaper: &str, favorite: bool) {
+        if favorite {
+            self.favorites.insert(wallpaper.to_string());
+        } else {
+            self.favorites.remove(wallpaper);
+        }
+    }
+
+    /// User tags of a wallpaper (without the automatic folder categories).
+    pub fn tags_of(&self, wallpaper: &str) -> Vec<String> {
+        self.tags.get(wallpaper).map(|t| t.iter().cloned().collect()).unwrap_or_default()
+    }
+
+    /// Replace a wallpaper's tags. Tags are trimmed and lowercased; empty ones are dropped.
+    pub fn set_tags(&mut self, wallpaper: &str, tags: &[String]) {
+        let tags: BTreeSet<String> = tags
+            .iter()
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if tags.is_empty() {
+            self.tags.remove(wallpaper);
+        } else {
+            self.tags.insert(wallpaper.to_string(), tags);
+        }
+    }
+
+    /// Collections containing a wallpaper.
+    pub fn collections_of(&self, wallpaper: &str) -> Vec<String> {
+        self.collections
+            .iter()
+            .filter(|(_, members)| members.contains(wallpaper))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Put a wallpaper in exactly `names`; collections left empty are removed.
+    pub fn set_collections(&mut self, wallpaper: &str, names: &[String]) {
+        let names: BTreeSet<String> = names.iter().map(|n| n.trim().to_string()).filter(|n| !n.is_empty()).collect();
+        for (name, members) in self.collections.iter_mut() {
+            if !names.contains(name) {
+                members.remove(wallpaper);
+            }
+        }
+        for name in names {
+            self.collections.entry(name).or_default().insert(wallpaper.to_string());
+        }
+        self.collections.retain(|_, members| !members.is_empty());
+    }
+
+    /// Every tag in use, user tags and folder categories of `wallpapers` alike.
+    pub fn all_tags(&self, wallpapers: &[PathBuf], dirs: &[PathBuf]) -> Vec<String> {
+        let mut tags: BTreeSet<String> = self.tags.values().flatten().cloned().collect();
+        for wallpaper in wallpapers {
+            tags.extend(categories(wallpaper, dirs));
+        }
+        tags.into_iter().collect()
+    }
+
+    pub fn matches(&self, wallpaper: &Path, dirs: &[PathBuf], filter: &WallpaperFilter) -> bool {
+        let key = wallpaper.to_string_lossy();
+        match filter {
+            WallpaperFilter::All => true,
+            WallpaperFilter::Favorites => self.is_favorite(&key),
+            WallpaperFilter::Tag(tag) => {
+                self.tags.get(key.as_ref()).map(|t| t.contains(tag)).unwrap_or(false)
+                    || categories(wallpaper, dirs).contains(tag)
+            }
+            WallpaperFilter::Collection(name) => {
+                self.collections.get(name).map(|m| m.contains(key.as_ref())).unwrap_or(false)
+            }
+        }
+    }
+}
+
+/// Automatic tags from the subfolders between a wallpaper folder and the file,
+/// e.g. Wallpapers/Nature/Forest/a.jpg -> ["nature", "forest"].
+pub fn categories(wallpaper: &Path, dirs: &[PathBuf]) -> Vec<String> {
+    let Some(relative) = dirs.iter().find_map(|dir| wallpaper.strip_prefix(dir).ok()) else {
+        return Vec::new();
+    };
+    relative
+        .parent()
+        .map(|parent| {
+            parent
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_lowercase())
+                .collect()
+        })
+        .unwrap_or_default()
+}
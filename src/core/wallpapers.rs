@@ -0,0 +1,320 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::config::{expand_home, ColorConfig, DEFAULT_WALLPAPER_DEPTH};
+use crate::core::quickshell;
+use crate::core::thumbnails::ImageInfo;
+
+const IMAGE_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp", "gif"];
+const VIDEO_EXTENSIONS: &[&str] = &["mp4", "webm", "mkv", "mov"];
+
+/// How Quickshell has to render a wallpaper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WallpaperKind {
+    Image,
+    /// Animated GIF or WebP
+    Animated,
+    Video,
+}
+
+impl WallpaperKind {
+    /// Type hint written for Quickshell next to the wallpaper path.
+    pub fn key(&self) -> &'static str {
+        match self {
+            WallpaperKind::Image => "image",
+            WallpaperKind::Animated => "animated",
+            WallpaperKind::Video => "video",
+        }
+    }
+
+    /// Short badge text for tiles; None for still images.
+    pub fn badge(&self) -> Option<&'static str> {
+        match self {
+            WallpaperKind::Image => None,
+            WallpaperKind::Animated => Some("ANIM"),
+            WallpaperKind::Video => Some("VIDEO"),
+        }
+    }
+
+    /// Kind of `path`. GIF and WebP files are only animated if they have more than one frame,
+    /// which means reading their headers - avoid calling this for a whole folder on the main thread.
+    pub fn of(path: &Path) -> Self {
+        let ext = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+        if VIDEO_EXTENSIONS.contains(&ext.as_str()) {
+            WallpaperKind::Video
+        } else if (ext == "gif" || ext == "webp") && is_animated(path, &ext) {
+            WallpaperKind::Animated
+        } else {
+            WallpaperKind::Image
+        }
+    }
+}
+
+fn is_animated(path: &Path, ext: &str) -> bool {
+    use image::AnimationDecoder;
+
+    let Ok(file) = fs::File::open(path) else {
+        return false;
+    };
+    let reader = std::io::BufReader::new(file);
+    if ext == "gif" {
+        image::codecs::gif::GifDecoder::new(reader)
+            .map(|decoder| decoder.into_frames().take(2).count() > 1)
+            .unwrap_or(false)
+    } else {
+        image::codecs::webp::WebPDecoder::new(reader)
+            .map(|decoder| decoder.has_animation())
+            .unwrap_or(false)
+    }
+}
+
+pub fn is_video_file(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| VIDEO_EXTENSIONS.contains(&ext.to_string_lossy().to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+/// Configured wallpaper folders, or the default ~/Pictures/Wallpapers.
+pub fn source_dirs(config: &ColorConfig) -> Vec<PathBuf> {
+    match config.wallpaper_dirs {
+        Some(ref dirs) if !dirs.is_empty() => dirs.iter().map(|d| expand_home(d)).collect(),
+        _ => vec![quickshell::get_wallpapers_path()],
+    }
+}
+
+pub fn is_wallpaper_file(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| IMAGE_EXTENSIONS.contains(&ext.to_string_lossy().to_lowercase().as_str()))
+        .unwrap_or(false)
+        || is_video_file(path)
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name().map(|name| name.to_string_lossy().starts_with('.')).unwrap_or(false)
+}
+
+/// Walk `dir` and up to `depth` levels of subdirectories. `visit` gets every directory and file.
+fn walk(dir: &Path, depth: u8, show_hidden: bool, seen: &mut HashSet<PathBuf>, visit: &mut dyn FnMut(&Path, bool)) {
+    // Symlinked folders can point back up the tree
+    let Ok(canonical) = dir.canonicalize() else {
+        return;
+    };
+    if !seen.insert(canonical) {
+        return;
+    }
+    visit(dir, true);
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !show_hidden && is_hidden(&path) {
+            continue;
+        }
+        if path.is_dir() {
+            if depth > 0 {
+                walk(&path, depth - 1, show_hidden, seen, visit);
+            }
+        } else if path.is_file() {
+            visit(&path, false);
+        }
+    }
+}
+
+/// All wallpapers in the configured folders, sorted and without duplicates.
+pub fn find_wallpapers(config: &ColorConfig) -> Vec<PathBuf> {
+    find_wallpapers_in(&source_dirs(config), config)
+}
+
+/// Wallpapers in `dirs`, scanned with the configured depth and hidden-file setting.
+pub fn find_wallpapers_in(dirs: &[PathBuf], config: &ColorConfig) -> Vec<PathBuf> {
+    let depth = config.wallpaper_depth.unwrap_or(DEFAULT_WALLPAPER_DEPTH);
+    let show_hidden = config.wallpaper_show_hidden.unwrap_or(false);
+
+    let mut seen = HashSet::new();
+    let mut wallpapers = Vec::new();
+    for dir in dirs {
+        walk(dir, depth, show_hidden, &mut seen, &mut |path, is_dir| {
+            if !is_dir && is_wallpaper_file(path) {
+                wallpapers.push(path.to_path_buf());
+            }
+        });
+    }
+
+    wallpapers.sort();
+    wallpapers.dedup();
+    wallpapers
+}
+
+/// Every folder whose contents are scanned - monitors aren't recursive, so each needs its own.
+pub fn watched_dirs(config: &ColorConfig) -> Vec<PathBuf> {
+    let depth = config.wallpaper_depth.unwrap_or(DEFAULT_WALLPAPER_DEPTH);
+    let show_hidden = config.wallpaper_show_hidden.unwrap_or(false);
+
+    let mut seen = HashSet::new();
+    let mut dirs = Vec::new();
+    for dir in source_dirs(config) {
+        walk(&dir, depth, show_hidden, &mut seen, &mut |path, is_dir| {
+            if is_dir {
+                dirs.push(path.to_path_buf());
+            }
+        });
+    }
+    dirs
+}
+
+/// Search bar and attribute filters of the wallpaper grid.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchCriteria {
+    /// Case-insensitive filename fragment
+    pub text: String,
+    /// Minimum height in px (0 = any)
+    pub min_height: u32,
+    /// Width / height to match, within a few percent
+    pub aspect: Option<f64>,
+    pub orientation: Option<Orientation>,
+    pub hue: Option<HueFamily>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Landscape,
+    Portrait,
+    Square,
+}
+
+/// Named hue ranges for the color filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HueFamily {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Purple,
+    Pink,
+    /// Mostly gray, black or white
+    Neutral,
+}
+
+pub const HUE_FAMILIES: &[(HueFamily, &str)] = &[
+    (HueFamily::Red, "Red"),
+    (HueFamily::Orange, "Orange"),
+    (HueFamily::Yellow, "Yellow"),
+    (HueFamily::Green, "Green"),
+    (HueFamily::Cyan, "Cyan"),
+    (HueFamily::Blue, "Blue"),
+    (HueFamily::Purple, "Purple"),
+    (HueFamily::Pink, "Pink"),
+    (HueFamily::Neutral, "Neutral"),
+];
+
+impl HueFamily {
+    pub fn of(hue: Option<f64>) -> Self {
+        let Some(h) = hue else {
+            return HueFamily::Neutral;
+        };
+        match h.rem_euclid(360.0) {
+            h if h < 15.0 => HueFamily::Red,
+            h if h < 45.0 => HueFamily::Orange,
+            h if h < 70.0 => HueFamily::Yellow,
+            h if h < 165.0 => HueFamily::Green,
+            h if h < 195.0 => HueFamily::Cyan,
+            h if h < 255.0 => HueFamily::Blue,
+            h if h < 290.0 => HueFamily::Purple,
+            h if h < 345.0 => HueFamily::Pink,
+            _ => HueFamily::Red,
+        }
+    }
+}
+
+impl SearchCriteria {
+    /// Whether image size or color is needed - those come from the thumbnail cache.
+    pub fn needs_image_info(&self) -> bool {
+        self.min_height > 0 || self.aspect.is_some() || self.orientation.is_some() || self.hue.is_some()
+    }
+
+    pub fn matches_name(&self, path: &Path) -> bool {
+        let text = self.text.trim().to_lowercase();
+        text.is_empty()
+            || path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_lowercase().contains(&text))
+                .unwrap_or(false)
+    }
+
+    pub fn matches_info(&self, info: &ImageInfo) -> bool {
+        if info.height < self.min_height {
+            return false;
+        }
+        let ratio = info.width as f64 / info.height.max(1) as f64;
+        if let Some(aspect) = self.aspect {
+            if (ratio - aspect).abs() / aspect > 0.03 {
+                return false;
+            }
+        }
+        if let Some(orientation) = self.orientation {
+            let actual = if (ratio - 1.0).abs() < 0.05 {
+                Orientation::Square
+            } else if ratio > 1.0 {
+                Orientation::Landscape
+            } else {
+                Orientation::Portrait
+            };
+            if actual != orientation {
+                return false;
+            }
+        }
+        if let Some(hue) = self.hue {
+            if HueFamily::of(info.hue) != hue {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Folder imported wallpapers go to: the first configured wallpaper folder.
+pub fn import_dir(config: &ColorConfig) -> PathBuf {
+    source_dirs(config).into_iter().next().unwrap_or_else(quickshell::get_wallpapers_path)
+}
+
+/// `name` in `dir`, or "stem (2).ext", "stem (3).ext", … if that's taken.
+fn unique_destination(dir: &Path, name: &std::ffi::OsStr) -> PathBuf {
+    let candidate = dir.join(name);
+    if !candidate.exists() && candidate.symlink_metadata().is_err() {
+        return candidate;
+    }
+    let original = Path::new(name);
+    let stem = original.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let extension = original.extension().map(|e| format!(".{}", e.to_string_lossy())).unwrap_or_default();
+    (2..)
+        .map(|n| dir.join(format!("{} ({}){}", stem, n, extension)))
+        .find(|p| !p.exists() && p.symlink_metadata().is_err())
+        .expect("unbounded range")
+}
+
+/// Copy (or symlink, with import mode "link") an image into the import folder.
+/// Returns where it ended up; files already in a wallpaper folder are left alone.
+pub fn import_wallpaper(source: &Path, config: &ColorConfig) -> std::io::Result<PathBuf> {
+    if !is_wallpaper_file(source) {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a supported image"));
+    }
+    if source_dirs(config).iter().any(|dir| source.starts_with(dir)) {
+        return Ok(source.to_path_buf());
+    }
+    let dir = import_dir(config);
+    fs::create_dir_all(&dir)?;
+
+    let name = source.file_name().ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, "no file name"))?;
+    let destination = unique_destination(&dir, name);
+    if config.wallpaper_import_mode.as_deref() == Some("link") {
+        std::os::unix::fs::symlink(source.canonicalize()?, &destination)?;
+    } else {
+        fs::copy(source, &destination)?;
+    }
+    Ok(destination)
+}
@@ -1,4 +1,5 @@
 mod app;
+mod cli;
 mod core;
 mod tabs;
 mod widgets;
@@ -8,6 +9,11 @@ use app::FuseApp;
 use crate::core::config::ColorConfig;
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(code) = cli::run(&args) {
+        std::process::exit(code);
+    }
+
     let config = ColorConfig::load();
     ColorConfig::apply_scale_env_from_config(&config);
     let app = FuseApp::new(config);
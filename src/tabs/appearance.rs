@@ -1,13 +1,30 @@
 use gtk4::prelude::*;
-use gtk4::{Box as GtkBox, Orientation, Label, ScrolledWindow, Button, FlowBox, Picture, Overlay, gdk};
+use gtk4::{Box as GtkBox, Orientation, Label, ScrolledWindow, Button, Entry, FlowBox, Picture, Overlay, Scale, Switch, gdk};
 use gtk4::gio;
+use std::cell::RefCell;
+use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use std::path::PathBuf;
-use std::fs;
 use std::collections::HashMap;
 
-use crate::core::config::ColorConfig;
+use crate::core::backgrounds;
+use crate::core::color;
+use crate::core::config::{ColorConfig, DEFAULT_WALLPAPER_DEPTH, MAX_CORNER_RADIUS, MAX_WALLPAPER_DEPTH};
+use crate::core::exporters::{self, Exporter};
+use crate::core::presets::{self, COLOR_PRESETS};
+use crate::core::monitors;
 use crate::core::quickshell;
+use crate::core::templates;
+use crate::core::slideshow::{self, SlideshowCommand};
+use crate::core::theme_schedule::{self, SunTimes};
+use crate::core::wallpaper_meta::{self, WallpaperFilter, WallpaperMeta};
+use crate::core::thumbnails;
+use crate::core::wallpapers::{self, SearchCriteria};
+use crate::widgets::color_picker;
+use crate::widgets::duplicates_dialog;
+use crate::widgets::font_picker::{self, FontKind};
+use crate::widgets::wallpaper_tile;
+use crate::widgets::layout_preview;
 
 fn schedule_notify_color_change_ms(ms: u32) {
     gtk4::glib::timeout_add_local(std::time::Duration::from_millis(ms as u64), move || {
@@ -55,24 +72,27 @@ fn set_box_background_color(box_widget: &gtk4::Box, color: &str) {
     box_widget.add_css_class(&color_class_for_preset(color));
 }
 
-// 8 new color presets, each with light and dark variants
-// Format: (name, theme, background, primary, secondary, text, accent)
-const COLOR_PRESETS: &[(&str, &str, &str, &str, &str, &str, &str)] = &[
-    // Preset 1: Midnight (Mono)
-    ("Midnight (Mono)", "light", "#ffffff", "#f5f5f5", "#e5e5e5", "#000000", "#333333"),
-    ("Midnight (Mono)", "dark", "#000000", "#121212", "#080808", "#ffffff", "#c0c0c0"),
-    // Preset 2: Gruvbox
-    ("Gruvbox", "light", "#fbf1c7", "#f2e5bc", "#ebdbb2", "#3c3836", "#af3a03"),
-    ("Gruvbox", "dark", "#282828", "#32302f", "#1d2021", "#ebdbb2", "#d65d0e"),
-    // Preset 3: Catppuccin
-    ("Catppuccin", "light", "#eff1f5", "#e6e9ef", "#ccd0da", "#4c4f69", "#8839ef"),
-    ("Catppuccin", "dark", "#24273a", "#363a4f", "#494d64", "#cad3f5", "#c6a0f6"),
-    // Preset 4: Nord
-    ("Nord", "light", "#eceff4", "#e5e9f0", "#d8dee9", "#2e3440", "#5e81ac"),
-    ("Nord", "dark", "#2e3440", "#3b4252", "#434c5e", "#eceff4", "#88c0d0"),
-    // Preset 5: Dracula
-    ("Dracula", "light", "#f8f8f2", "#e2e2e2", "#dcdcdc", "#282a36", "#6272a4"),
-    ("Dracula", "dark", "#282a36", "#44475a", "#6272a4", "#f8f8f2", "#bd93f9"),
+// Corner radius presets (label, px). "Rounded" matches the stock stylesheet.
+const RADIUS_PRESETS: &[(&str, u8)] = &[
+    ("Sharp", 0),
+    ("Small", 3),
+    ("Rounded", 6),
+    ("Large", 12),
+];
+
+// Quickshell surfaces with their own background opacity (config key, label)
+const TRANSPARENT_SURFACES: &[(&str, &str)] = &[
+    ("sidebar", "Sidebar"),
+    ("dashboard", "Dashboard"),
+    ("notifications", "Notifications"),
+    ("lockscreen", "Lock Screen"),
+];
+
+const STATUS_ROLES: &[(&str, &str, &str)] = &[
+    ("error", "Error", "Failures, disconnected devices, critical battery"),
+    ("warning", "Warning", "Low battery, degraded connections"),
+    ("success", "Success", "Connected, charging, completed actions"),
+    ("info", "Info", "Neutral notices and hints"),
 ];
 
 pub struct AppearanceTab {
@@ -122,6 +142,49 @@ impl AppearanceTab {
         presets_card.append(&create_colors_section(Arc::clone(&config)));
         content.append(&presets_card);
 
+        // --- Status Colors Group ---
+        add_group_header(&content, "Status Colors");
+        let status_card = GtkBox::new(Orientation::Vertical, 0);
+        status_card.add_css_class("card");
+        status_card.append(&create_status_colors_section(Arc::clone(&config)));
+        content.append(&status_card);
+
+        // --- Fonts Group ---
+        add_group_header(&content, "Fonts");
+        let fonts_card = GtkBox::new(Orientation::Vertical, 0);
+        fonts_card.add_css_class("card");
+        fonts_card.append(&create_font_row("Interface Font", FontKind::Ui, Arc::clone(&config)));
+        fonts_card.append(&create_font_row("Monospace Font", FontKind::Mono, Arc::clone(&config)));
+        fonts_card.append(&create_font_row("Icon Font", FontKind::Icon, Arc::clone(&config)));
+        content.append(&fonts_card);
+
+        // --- Transparency Group ---
+        add_group_header(&content, "Transparency");
+        content.append(&layout_preview::create_layout_preview(&config.lock().unwrap()));
+        let transparency_card = GtkBox::new(Orientation::Vertical, 0);
+        transparency_card.add_css_class("card");
+        for (surface, label) in TRANSPARENT_SURFACES {
+            transparency_card.append(&create_opacity_row(label, surface, Arc::clone(&config)));
+        }
+        transparency_card.append(&create_blur_row(Arc::clone(&config)));
+        content.append(&transparency_card);
+
+        // --- Export Group ---
+        add_group_header(&content, "Export to Applications");
+        let export_card = GtkBox::new(Orientation::Vertical, 0);
+        export_card.add_css_class("card");
+        for exporter in exporters::ALL_EXPORTERS {
+            export_card.append(&create_export_row(*exporter, Arc::clone(&config)));
+        }
+        content.append(&export_card);
+
+        // --- Templates Group ---
+        add_group_header(&content, "Color Templates");
+        let templates_card = GtkBox::new(Orientation::Vertical, 0);
+        templates_card.add_css_class("card");
+        templates_card.append(&create_templates_section(Arc::clone(&config)));
+        content.append(&templates_card);
+
         // --- Wallpapers Group ---
         add_group_header(&content, "Wallpapers");
         let wallpapers_card = GtkBox::new(Orientation::Vertical, 0);
@@ -129,6 +192,13 @@ impl AppearanceTab {
         wallpapers_card.append(&create_background_section(Arc::clone(&config)));
         content.append(&wallpapers_card);
 
+        // --- Slideshow Group ---
+        add_group_header(&content, "Slideshow");
+        let slideshow_card = GtkBox::new(Orientation::Vertical, 0);
+        slideshow_card.add_css_class("card");
+        slideshow_card.append(&create_slideshow_section(Arc::clone(&config)));
+        content.append(&slideshow_card);
+
         scrolled.set_child(Some(&content));
 
         Self {
@@ -175,91 +245,62 @@ fn create_style_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
     let current_bg = current_config.background.clone();
     drop(current_config);
     
-    // Helper function to check if color is light
-    let is_light = |color: &str| -> bool {
-        let hex = color.trim_start_matches('#');
-        if hex.len() == 6 {
-            if let (Ok(r), Ok(g), Ok(b)) = (
-                u8::from_str_radix(&hex[0..2], 16),
-                u8::from_str_radix(&hex[2..4], 16),
-                u8::from_str_radix(&hex[4..6], 16),
-            ) {
-                let brightness = (r as f32 * 0.299 + g as f32 * 0.587 + b as f32 * 0.114) / 255.0;
-                return brightness > 0.5;
-            }
-        }
-        false
-    };
+    let is_current_light = color::is_light(&current_bg);
+    let is_auto = config.lock().unwrap().theme_mode.as_deref() == Some("auto");
     
-    let is_current_light = is_light(&current_bg);
+    let light_card = create_theme_card("Light", "light", !is_auto && is_current_light);
+    let dark_card = create_theme_card("Dark", "dark", !is_auto && !is_current_light);
+    let auto_card = create_theme_card("Automatic", "auto", is_auto);
+    let theme_cards = vec![light_card.clone(), dark_card.clone(), auto_card.clone()];
+
+    // Schedule settings are only relevant in automatic mode
+    let schedule_box = create_theme_schedule_box(Arc::clone(&config));
+    schedule_box.set_visible(is_auto);
     
-    let light_card = create_theme_card("Light", "light", is_current_light);
-    let dark_card = create_theme_card("Dark", "dark", !is_current_light);
-    
-    let light_card_clone = light_card.clone();
-    let dark_card_clone = dark_card.clone();
-    
-    // Connect Light card click
-    {
-        let dark_ref = dark_card_clone.clone();
+    // Connect Light / Dark card clicks - picking one by hand turns the schedule off
+    for (card, variant) in [(&light_card, "light"), (&dark_card, "dark")] {
         let config_clone = Arc::clone(&config);
-        light_card.connect_clicked(move |btn| {
-            btn.add_css_class("theme-card-selected");
-            dark_ref.remove_css_class("theme-card-selected");
-            
-            let current_cfg = config_clone.lock().unwrap();
-            let preset_name = current_cfg.color_preset.clone().unwrap_or_else(|| "Ocean Breeze".to_string());
-            drop(current_cfg);
-            
-            let light_preset = COLOR_PRESETS.iter()
-                .find(|p| p.0 == preset_name && p.1 == "light")
-                .or_else(|| COLOR_PRESETS.iter().find(|p| p.1 == "light"));
-            
-            if let Some(light_preset) = light_preset {
-                let mut cfg = ColorConfig::load();
-                cfg.update_colors(light_preset.2, light_preset.3, light_preset.4, light_preset.5, light_preset.6);
-                cfg.set_preset(light_preset.0);
-                if let Err(_e) = cfg.save() {
-                } else {
-                    *config_clone.lock().unwrap() = cfg.clone();
-                    schedule_notify_color_change_ms(300);
-                }
+        let theme_cards = theme_cards.clone();
+        let schedule_box = schedule_box.clone();
+        card.connect_clicked(move |btn| {
+            select_theme_card(&theme_cards, btn);
+            schedule_box.set_visible(false);
+
+            let mut cfg = ColorConfig::load();
+            cfg.set_theme_mode("manual");
+            presets::apply_any_variant(&mut cfg, variant);
+            if cfg.save().is_ok() {
+                *config_clone.lock().unwrap() = cfg.clone();
+                schedule_notify_color_change_ms(300);
             }
         });
     }
-    
-    // Connect Dark card click
+
+    // Connect Automatic card click
     {
-        let light_ref = light_card_clone.clone();
         let config_clone = Arc::clone(&config);
-        dark_card.connect_clicked(move |btn| {
-            btn.add_css_class("theme-card-selected");
-            light_ref.remove_css_class("theme-card-selected");
-            
-            let current_cfg = config_clone.lock().unwrap();
-            let preset_name = current_cfg.color_preset.clone().unwrap_or_else(|| "Ocean Breeze".to_string());
-            drop(current_cfg);
-            
-            let dark_preset = COLOR_PRESETS.iter()
-                .find(|p| p.0 == preset_name && p.1 == "dark")
-                .or_else(|| COLOR_PRESETS.iter().find(|p| p.1 == "dark"));
-            
-            if let Some(dark_preset) = dark_preset {
-                let mut cfg = ColorConfig::load();
-                cfg.update_colors(dark_preset.2, dark_preset.3, dark_preset.4, dark_preset.5, dark_preset.6);
-                cfg.set_preset(dark_preset.0);
-                if let Err(_e) = cfg.save() {
-                } else {
-                    *config_clone.lock().unwrap() = cfg.clone();
-                    schedule_notify_color_change_ms(300);
-                }
+        let theme_cards = theme_cards.clone();
+        let schedule_box = schedule_box.clone();
+        auto_card.connect_clicked(move |btn| {
+            select_theme_card(&theme_cards, btn);
+            schedule_box.set_visible(true);
+
+            let mut cfg = ColorConfig::load();
+            cfg.set_theme_mode("auto");
+            // Apply the variant for the current time right away instead of waiting for the timer
+            theme_schedule::apply_schedule(&mut cfg);
+            if cfg.save().is_ok() {
+                *config_clone.lock().unwrap() = cfg.clone();
+                schedule_notify_color_change_ms(300);
             }
         });
     }
     
     cards_container.append(&light_card);
     cards_container.append(&dark_card);
+    cards_container.append(&auto_card);
     theme_col.append(&cards_container);
+    theme_col.append(&schedule_box);
     
     // --- ROUNDING COLUMN ---
     let rounding_col = GtkBox::new(Orientation::Vertical, 0);
@@ -267,7 +308,7 @@ fn create_style_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
 
     // Rounding Header
     let rounding_header = GtkBox::new(Orientation::Vertical, 0);
-    let rounding_desc = Label::new(Some("Select corner style"));
+    let rounding_desc = Label::new(Some("Select corner radius"));
     rounding_desc.add_css_class("row-description");
     rounding_desc.set_xalign(0.0);
     rounding_desc.set_margin_start(16);
@@ -283,63 +324,543 @@ fn create_style_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
     buttons_move.set_halign(gtk4::Align::Center);
     buttons_move.set_valign(gtk4::Align::Center);
     
-    let current_rounding = config.lock().unwrap().rounding.clone().unwrap_or_else(|| "rounded".to_string());
-    let is_rounded = current_rounding == "rounded";
-    let is_sharp = current_rounding == "sharp";
+    let current_radius = config.lock().unwrap().corner_radius();
+
+    // Preset buttons
+    let mut preset_buttons = Vec::new();
+    for (label, _) in RADIUS_PRESETS {
+        let button = Button::with_label(label);
+        button.add_css_class("rounding-button");
+        buttons_move.append(&button);
+        preset_buttons.push(button);
+    }
+    let highlight_preset = {
+        let preset_buttons = preset_buttons.clone();
+        move |radius: u8| {
+            for (button, (_, value)) in preset_buttons.iter().zip(RADIUS_PRESETS) {
+                if *value == radius {
+                    button.add_css_class("suggested-action");
+                } else {
+                    button.remove_css_class("suggested-action");
+                }
+            }
+        }
+    };
+    highlight_preset(current_radius);
+    rounding_col.append(&buttons_move);
 
-    let rounded_button = Button::with_label("Rounded");
-    rounded_button.add_css_class("rounding-button");
-    if is_rounded {
-        rounded_button.add_css_class("suggested-action");
+    // Fine-grained slider
+    let scale = Scale::with_range(Orientation::Horizontal, 0.0, MAX_CORNER_RADIUS as f64, 1.0);
+    scale.set_value(current_radius as f64);
+    scale.set_digits(0);
+    scale.set_draw_value(true);
+    scale.set_value_pos(gtk4::PositionType::Right);
+    scale.set_hexpand(true);
+    for (_, value) in RADIUS_PRESETS {
+        scale.add_mark(*value as f64, gtk4::PositionType::Bottom, None);
     }
-    let sharp_button = Button::with_label("Sharp");
-    sharp_button.add_css_class("rounding-button");
-    if is_sharp {
-        sharp_button.add_css_class("suggested-action");
+    let slider_row = create_card_row("Corner Radius", scale.clone());
+    rounding_col.append(&slider_row);
+
+    for (button, (_, value)) in preset_buttons.iter().zip(RADIUS_PRESETS) {
+        let scale = scale.clone();
+        let value = *value;
+        // The slider handler does the saving
+        button.connect_clicked(move |_| scale.set_value(value as f64));
     }
-    
-    // Rounding Logic
+
+    // Save after the slider settles - Quickshell and the CSS reload on every save
     {
         let config = Arc::clone(&config);
-        let sharp_btn = sharp_button.clone();
-        rounded_button.connect_clicked(move |btn| {
+        let debounce_id = Arc::new(Mutex::new(Option::<gtk4::glib::SourceId>::None));
+        scale.connect_value_changed(move |s| {
+            let radius = s.value().round() as u8;
+            highlight_preset(radius);
+
+            let mut db_id = debounce_id.lock().unwrap();
+            if let Some(id) = db_id.take() {
+                id.remove();
+            }
+            let config = Arc::clone(&config);
+            let debounce_id_inner = debounce_id.clone();
+            *db_id = Some(gtk4::glib::timeout_add_local(std::time::Duration::from_millis(300), move || {
+                debounce_id_inner.lock().unwrap().take();
+                let mut cfg = ColorConfig::load();
+                cfg.set_corner_radius(radius);
+                if cfg.save().is_ok() {
+                    *config.lock().unwrap() = cfg.clone();
+                    schedule_notify_color_change_ms(200);
+                }
+                gtk4::glib::ControlFlow::Break
+            }));
+        });
+    }
+
+    // Append columns
+    container.append(&theme_col);
+    container.append(&rounding_col);
+
+    container
+}
+
+fn create_status_colors_section(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let section = GtkBox::new(Orientation::Vertical, 0);
+    let current = config.lock().unwrap().clone();
+
+    for (role, title, description) in STATUS_ROLES {
+        let config = Arc::clone(&config);
+        let role = role.to_string();
+        section.append(&color_picker::create_color_picker_row(
+            title,
+            Some(description),
+            &current.status_color(&role),
+            move |color| {
+                let mut cfg = ColorConfig::load();
+                cfg.set_status_color(&role, &color);
+                if cfg.save().is_ok() {
+                    *config.lock().unwrap() = cfg.clone();
+                    schedule_notify_color_change_ms(200);
+                }
+            },
+        ));
+    }
+
+    section
+}
+
+fn create_font_row(label: &str, kind: FontKind, config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let current = config.lock().unwrap().clone();
+    let (family, size) = match kind {
+        FontKind::Ui => (current.ui_font.clone(), Some(current.ui_font_size.unwrap_or(11))),
+        FontKind::Mono => (current.mono_font.clone(), Some(current.mono_font_size.unwrap_or(11))),
+        // Icon glyphs follow the size of the text around them
+        FontKind::Icon => (current.icon_font.clone(), None),
+    };
+
+    let family_config = Arc::clone(&config);
+    let picker = font_picker::create_font_picker(
+        kind,
+        family.as_deref(),
+        size,
+        move |family| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_font(kind.key(), family.as_deref());
+            if cfg.save().is_ok() {
+                *family_config.lock().unwrap() = cfg.clone();
+                schedule_notify_color_change_ms(200);
+            }
+        },
+        move |size| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_font_size(kind.key(), size);
+            if cfg.save().is_ok() {
+                *config.lock().unwrap() = cfg.clone();
+                schedule_notify_color_change_ms(200);
+            }
+        },
+    );
+
+    create_card_row(label, picker)
+}
+
+fn create_opacity_row(label: &str, surface: &'static str, config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let scale = Scale::with_range(Orientation::Horizontal, 0.0, 100.0, 5.0);
+    scale.set_value(config.lock().unwrap().surface_opacity(surface) as f64);
+    scale.set_digits(0);
+    scale.set_draw_value(true);
+    scale.set_value_pos(gtk4::PositionType::Right);
+    scale.set_format_value_func(|_, value| format!("{}%", value.round() as u32));
+    scale.set_size_request(240, -1);
+
+    let debounce_id = Arc::new(Mutex::new(Option::<gtk4::glib::SourceId>::None));
+    scale.connect_value_changed(move |s| {
+        let value = s.value().round() as u8;
+        // Preview right away, save once the slider settles
+        layout_preview::preview_update(|c| c.set_surface_opacity(surface, value));
+
+        let mut db_id = debounce_id.lock().unwrap();
+        if let Some(id) = db_id.take() {
+            id.remove();
+        }
+        let config = Arc::clone(&config);
+        let debounce_id_inner = debounce_id.clone();
+        *db_id = Some(gtk4::glib::timeout_add_local(std::time::Duration::from_millis(300), move || {
+            debounce_id_inner.lock().unwrap().take();
             let mut cfg = ColorConfig::load();
-            cfg.set_rounding("rounded");
-            cfg.set_quickshell_border_radius(6);
-            if let Err(_e) = cfg.save() {
-            } else {
+            cfg.set_surface_opacity(surface, value);
+            if cfg.save().is_ok() {
+                *config.lock().unwrap() = cfg.clone();
+                schedule_notify_color_change_ms(200);
+            }
+            gtk4::glib::ControlFlow::Break
+        }));
+    });
+
+    create_card_row(label, scale)
+}
+
+fn create_blur_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let switch = Switch::new();
+    switch.set_active(config.lock().unwrap().blur_enabled.unwrap_or(false));
+    switch.set_valign(gtk4::Align::Center);
+
+    switch.connect_active_notify(move |s| {
+        layout_preview::preview_update(|c| c.set_blur_enabled(s.is_active()));
+        let mut cfg = ColorConfig::load();
+        cfg.set_blur_enabled(s.is_active());
+        if cfg.save().is_ok() {
+            *config.lock().unwrap() = cfg.clone();
+            schedule_notify_color_change_ms(200);
+        }
+    });
+
+    create_card_row("Blur Behind Panels", switch)
+}
+
+fn create_export_row(exporter: Exporter, config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let row = GtkBox::new(Orientation::Horizontal, 12);
+    row.add_css_class("card-row");
+    row.set_valign(gtk4::Align::Center);
+
+    let text_box = GtkBox::new(Orientation::Vertical, 2);
+    text_box.set_hexpand(true);
+
+    let title = Label::new(Some(exporter.label()));
+    title.add_css_class("row-title");
+    title.set_halign(gtk4::Align::Start);
+    text_box.append(&title);
+
+    let path = Label::new(Some(&exporter.output_path().to_string_lossy()));
+    path.add_css_class("row-description");
+    path.set_halign(gtk4::Align::Start);
+    path.set_ellipsize(gtk4::pango::EllipsizeMode::Middle);
+    text_box.append(&path);
+
+    row.append(&text_box);
+
+    let switch = Switch::new();
+    switch.set_active(exporter.is_enabled(&config.lock().unwrap()));
+    switch.set_valign(gtk4::Align::Center);
+
+    switch.connect_active_notify(move |s| {
+        let mut cfg = ColorConfig::load();
+        exporter.set_enabled(&mut cfg, s.is_active());
+        if cfg.save().is_ok() {
+            *config.lock().unwrap() = cfg.clone();
+            // Write the current palette right away instead of waiting for the next color change
+            if s.is_active() {
+                let _ = exporter.export(&cfg);
+            }
+        }
+    });
+
+    row.append(&switch);
+    row
+}
+
+fn create_templates_section(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let section = GtkBox::new(Orientation::Vertical, 0);
+
+    // Folder row
+    let folder_row = GtkBox::new(Orientation::Horizontal, 12);
+    folder_row.add_css_class("card-row");
+    folder_row.set_valign(gtk4::Align::Center);
+
+    let folder_text = GtkBox::new(Orientation::Vertical, 2);
+    folder_text.set_hexpand(true);
+    let folder_title = Label::new(Some("Templates Folder"));
+    folder_title.add_css_class("row-title");
+    folder_title.set_halign(gtk4::Align::Start);
+    folder_text.append(&folder_title);
+    let folder_desc = Label::new(Some(
+        "Placeholders: {{accent}}, {{background.rgb}}, {{text.lighten(10)}}. Outputs are listed in templates.json",
+    ));
+    folder_desc.add_css_class("row-description");
+    folder_desc.set_halign(gtk4::Align::Start);
+    folder_desc.set_wrap(true);
+    folder_desc.set_xalign(0.0);
+    folder_text.append(&folder_desc);
+    folder_row.append(&folder_text);
+
+    let open_button = Button::with_label("Open");
+    open_button.add_css_class("flat");
+    open_button.set_valign(gtk4::Align::Center);
+    open_button.connect_clicked(|_| {
+        if let Ok(dir) = templates::ensure_templates_dir() {
+            let _ = std::process::Command::new("xdg-open").arg(&dir).spawn();
+        }
+    });
+    folder_row.append(&open_button);
+    section.append(&folder_row);
+
+    // One row per manifest entry
+    let entries = templates::load_manifest();
+    if entries.is_empty() {
+        let empty = Label::new(Some("No templates configured"));
+        empty.add_css_class("dim-label");
+        empty.set_margin_top(12);
+        empty.set_margin_bottom(12);
+        section.append(&empty);
+    }
+    for entry in entries.iter() {
+        let row = GtkBox::new(Orientation::Vertical, 2);
+        row.add_css_class("card-row");
+
+        let name = Label::new(Some(&entry.template));
+        name.add_css_class("row-title");
+        name.set_halign(gtk4::Align::Start);
+        row.append(&name);
+
+        let output = match entry.post_hook {
+            Some(ref hook) if !hook.trim().is_empty() => format!("→ {}  •  then: {}", entry.output, hook),
+            _ => format!("→ {}", entry.output),
+        };
+        let desc = Label::new(Some(&output));
+        desc.add_css_class("row-description");
+        desc.set_halign(gtk4::Align::Start);
+        desc.set_ellipsize(gtk4::pango::EllipsizeMode::Middle);
+        if !entry.enabled {
+            desc.add_css_class("dim-label");
+        }
+        row.append(&desc);
+
+        section.append(&row);
+    }
+
+    // Render row
+    let render_row = GtkBox::new(Orientation::Horizontal, 12);
+    render_row.add_css_class("card-row");
+    render_row.set_valign(gtk4::Align::Center);
+
+    let status = Label::new(Some("Templates render automatically on every color change"));
+    status.add_css_class("row-description");
+    status.set_hexpand(true);
+    status.set_halign(gtk4::Align::Start);
+    status.set_wrap(true);
+    status.set_xalign(0.0);
+    render_row.append(&status);
+
+    let render_button = Button::with_label("Render Now");
+    render_button.add_css_class("suggested-action");
+    render_button.set_valign(gtk4::Align::Center);
+    render_button.connect_clicked(move |_| {
+        let cfg = config.lock().unwrap().clone();
+        let errors = templates::render_all(&cfg);
+        if errors.is_empty() {
+            status.set_text("All templates rendered");
+        } else {
+            let lines: Vec<String> = errors.iter().map(|(t, e)| format!("{}: {}", t, e)).collect();
+            status.set_text(&lines.join("\n"));
+        }
+    });
+    render_row.append(&render_button);
+    section.append(&render_row);
+
+    section
+}
+
+fn create_card_row(label: &str, widget: impl IsA<gtk4::Widget>) -> GtkBox {
+    let row = GtkBox::new(Orientation::Horizontal, 12);
+    row.add_css_class("card-row");
+    row.set_valign(gtk4::Align::Center);
+
+    let l = Label::new(Some(label));
+    l.add_css_class("row-title");
+    l.set_hexpand(true);
+    l.set_halign(gtk4::Align::Start);
+    
+    row.append(&l);
+    row.append(&widget);
+    row
+}
+
+fn select_theme_card(cards: &[Button], selected: &Button) {
+    for card in cards {
+        if card == selected {
+            card.add_css_class("theme-card-selected");
+        } else {
+            card.remove_css_class("theme-card-selected");
+        }
+    }
+}
+
+fn sun_times_text(config: &ColorConfig) -> String {
+    match theme_schedule::sun_times_today(config) {
+        Some(SunTimes::Normal { sunrise, sunset }) => format!(
+            "Today: light from {}, dark from {}",
+            theme_schedule::format_time(sunrise),
+            theme_schedule::format_time(sunset)
+        ),
+        Some(SunTimes::AlwaysUp) => "Today: the sun doesn't set - staying light".to_string(),
+        Some(SunTimes::AlwaysDown) => "Today: the sun doesn't rise - staying dark".to_string(),
+        None => "Enter your coordinates to use sunrise and sunset".to_string(),
+    }
+}
+
+/// Run `f` once `entry` has been quiet for 600 ms, so typing doesn't save on every key.
+fn connect_debounced_changed<F>(entry: &Entry, f: F)
+where
+    F: Fn(&Entry) + 'static,
+{
+    let f = std::rc::Rc::new(f);
+    let debounce_id = Arc::new(Mutex::new(Option::<gtk4::glib::SourceId>::None));
+    entry.connect_changed(move |e| {
+        let mut db_id = debounce_id.lock().unwrap();
+        if let Some(id) = db_id.take() {
+            id.remove();
+        }
+        let f = f.clone();
+        let e = e.clone();
+        let debounce_id_inner = debounce_id.clone();
+        *db_id = Some(gtk4::glib::timeout_add_local(std::time::Duration::from_millis(600), move || {
+            debounce_id_inner.lock().unwrap().take();
+            f(&e);
+            gtk4::glib::ControlFlow::Break
+        }));
+    });
+}
+
+fn create_theme_schedule_box(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let container = GtkBox::new(Orientation::Vertical, 0);
+    container.set_margin_bottom(12);
+    let current = config.lock().unwrap().clone();
+    let use_sun = current.theme_schedule.as_deref() == Some("sun");
+
+    // Schedule source: fixed times or sunrise/sunset
+    let source_box = GtkBox::new(Orientation::Horizontal, 6);
+    let times_button = Button::with_label("Custom Times");
+    let sun_button = Button::with_label("Sunrise & Sunset");
+    if use_sun {
+        sun_button.add_css_class("suggested-action");
+    } else {
+        times_button.add_css_class("suggested-action");
+    }
+    source_box.append(&times_button);
+    source_box.append(&sun_button);
+    container.append(&create_card_row("Switch At", source_box.clone()));
+
+    // Custom times
+    let times_box = GtkBox::new(Orientation::Vertical, 0);
+    let light_entry = Entry::new();
+    light_entry.set_text(current.light_time.as_deref().unwrap_or("07:00"));
+    light_entry.set_placeholder_text(Some("HH:MM"));
+    light_entry.set_width_chars(6);
+    light_entry.set_valign(gtk4::Align::Center);
+    let dark_entry = Entry::new();
+    dark_entry.set_text(current.dark_time.as_deref().unwrap_or("19:00"));
+    dark_entry.set_placeholder_text(Some("HH:MM"));
+    dark_entry.set_width_chars(6);
+    dark_entry.set_valign(gtk4::Align::Center);
+    times_box.append(&create_card_row("Light From", light_entry.clone()));
+    times_box.append(&create_card_row("Dark From", dark_entry.clone()));
+    times_box.set_visible(!use_sun);
+    container.append(&times_box);
+
+    for entry in [&light_entry, &dark_entry] {
+        let config = Arc::clone(&config);
+        let light_entry = light_entry.clone();
+        let dark_entry = dark_entry.clone();
+        connect_debounced_changed(entry, move |e| {
+            if theme_schedule::parse_time(&e.text()).is_none() {
+                e.add_css_class("error");
+                return;
+            }
+            e.remove_css_class("error");
+            let (light, dark) = (light_entry.text(), dark_entry.text());
+            if theme_schedule::parse_time(&light).is_none() || theme_schedule::parse_time(&dark).is_none() {
+                return;
+            }
+            let mut cfg = ColorConfig::load();
+            cfg.set_theme_times(&light, &dark);
+            theme_schedule::apply_schedule(&mut cfg);
+            if cfg.save().is_ok() {
                 *config.lock().unwrap() = cfg.clone();
-                btn.add_css_class("suggested-action");
-                sharp_btn.remove_css_class("suggested-action");
                 schedule_notify_color_change_ms(200);
             }
         });
     }
 
-    {
+    // Sunrise / sunset from coordinates (computed offline)
+    let sun_box = GtkBox::new(Orientation::Vertical, 0);
+    let lat_entry = Entry::new();
+    lat_entry.set_text(&current.latitude.map(|v| v.to_string()).unwrap_or_default());
+    lat_entry.set_placeholder_text(Some("e.g. 52.23"));
+    lat_entry.set_width_chars(10);
+    lat_entry.set_valign(gtk4::Align::Center);
+    let lon_entry = Entry::new();
+    lon_entry.set_text(&current.longitude.map(|v| v.to_string()).unwrap_or_default());
+    lon_entry.set_placeholder_text(Some("e.g. 21.01"));
+    lon_entry.set_width_chars(10);
+    lon_entry.set_valign(gtk4::Align::Center);
+    sun_box.append(&create_card_row("Latitude", lat_entry.clone()));
+    sun_box.append(&create_card_row("Longitude", lon_entry.clone()));
+
+    let sun_info = Label::new(Some(&sun_times_text(&current)));
+    sun_info.add_css_class("row-description");
+    sun_info.set_halign(gtk4::Align::Start);
+    sun_info.set_margin_start(16);
+    sun_info.set_margin_top(8);
+    sun_box.append(&sun_info);
+    sun_box.set_visible(use_sun);
+    container.append(&sun_box);
+
+    for entry in [&lat_entry, &lon_entry] {
         let config = Arc::clone(&config);
-        let rounded_btn = rounded_button.clone();
-        sharp_button.connect_clicked(move |btn| {
+        let lat_entry = lat_entry.clone();
+        let lon_entry = lon_entry.clone();
+        let sun_info = sun_info.clone();
+        connect_debounced_changed(entry, move |_| {
+            let lat = lat_entry.text().trim().parse::<f64>().ok().filter(|v| (-90.0..=90.0).contains(v));
+            let lon = lon_entry.text().trim().parse::<f64>().ok().filter(|v| (-180.0..=180.0).contains(v));
+            for (e, valid) in [(&lat_entry, lat.is_some()), (&lon_entry, lon.is_some())] {
+                if valid || e.text().trim().is_empty() {
+                    e.remove_css_class("error");
+                } else {
+                    e.add_css_class("error");
+                }
+            }
+            let (Some(lat), Some(lon)) = (lat, lon) else {
+                return;
+            };
             let mut cfg = ColorConfig::load();
-            cfg.set_rounding("sharp");
-            cfg.set_quickshell_border_radius(0);
-            if let Err(_e) = cfg.save() {
-            } else {
+            cfg.set_location(lat, lon);
+            theme_schedule::apply_schedule(&mut cfg);
+            if cfg.save().is_ok() {
+                sun_info.set_text(&sun_times_text(&cfg));
                 *config.lock().unwrap() = cfg.clone();
-                btn.add_css_class("suggested-action");
-                rounded_btn.remove_css_class("suggested-action");
                 schedule_notify_color_change_ms(200);
             }
         });
     }
-    
-    buttons_move.append(&rounded_button);
-    buttons_move.append(&sharp_button);
-    rounding_col.append(&buttons_move);
 
-    // Append columns
-    container.append(&theme_col);
-    container.append(&rounding_col);
+    // Source switching
+    for (button, schedule) in [(&times_button, "times"), (&sun_button, "sun")] {
+        let config = Arc::clone(&config);
+        let times_button = times_button.clone();
+        let sun_button = sun_button.clone();
+        let times_box = times_box.clone();
+        let sun_box = sun_box.clone();
+        button.connect_clicked(move |_| {
+            let use_sun = schedule == "sun";
+            if use_sun {
+                sun_button.add_css_class("suggested-action");
+                times_button.remove_css_class("suggested-action");
+            } else {
+                times_button.add_css_class("suggested-action");
+                sun_button.remove_css_class("suggested-action");
+            }
+            times_box.set_visible(!use_sun);
+            sun_box.set_visible(use_sun);
+
+            let mut cfg = ColorConfig::load();
+            cfg.set_theme_schedule(schedule);
+            theme_schedule::apply_schedule(&mut cfg);
+            if cfg.save().is_ok() {
+                *config.lock().unwrap() = cfg.clone();
+                schedule_notify_color_change_ms(200);
+            }
+        });
+    }
 
     container
 }
@@ -387,23 +908,7 @@ fn create_colors_section(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
 
     let current_bg = config.lock().unwrap().background.clone();
     
-    // Helper function to check if color is light
-    let is_light = |color: &str| -> bool {
-        let hex = color.trim_start_matches('#');
-        if hex.len() == 6 {
-            if let (Ok(r), Ok(g), Ok(b)) = (
-                u8::from_str_radix(&hex[0..2], 16),
-                u8::from_str_radix(&hex[2..4], 16),
-                u8::from_str_radix(&hex[4..6], 16),
-            ) {
-                let brightness = (r as f32 * 0.299 + g as f32 * 0.587 + b as f32 * 0.114) / 255.0;
-                return brightness > 0.5;
-            }
-        }
-        false
-    };
-    
-    let is_current_light = is_light(&current_bg);
+    let is_current_light = color::is_light(&current_bg);
 
     // Presets FlowBox
     let flowbox = FlowBox::new();
@@ -554,6 +1059,7 @@ fn create_preset_card_with_variants(
         // For now, apply dark variant (could add theme selection later)
         let mut cfg = ColorConfig::load();
         cfg.update_colors(&dark_bg, &dark_primary, &dark_secondary, &dark_text, &dark_accent);
+        presets::apply_status_colors(&mut cfg, &name, "dark");
         cfg.set_preset(&name);
         if let Err(_e) = cfg.save() {
         } else {
@@ -631,11 +1137,12 @@ fn create_preset_card_single(
     let text = text.to_string();
     let accent = accent.to_string();
     let name = name.to_string();
-    let _theme = theme.to_string();
+    let theme = theme.to_string();
 
     button.connect_clicked(move |_| {
         let mut cfg = ColorConfig::load();
         cfg.update_colors(&bg, &primary, &secondary, &text, &accent);
+        presets::apply_status_colors(&mut cfg, &name, &theme);
         cfg.set_preset(&name);
         if let Err(_e) = cfg.save() {
         } else {
@@ -658,6 +1165,9 @@ fn create_background_section(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
     grid_container.set_margin_bottom(16);
     grid_container.set_margin_top(12);
 
+    // One drop target per output for monitor-specific wallpapers
+    grid_container.append(&create_monitor_strip(Arc::clone(&config)));
+
     let flowbox = FlowBox::new();
     flowbox.set_column_spacing(12);
     flowbox.set_row_spacing(12);
@@ -679,27 +1189,48 @@ fn create_background_section(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
     expanded_flowbox.set_homogeneous(true);
     expanded_flowbox.set_visible(false);
 
-    let flowbox_c = flowbox.clone();
-    let expanded_c = expanded_flowbox.clone();
-    let config_c = Arc::clone(&config);
-    gtk4::glib::MainContext::default().spawn_local(async move {
-        let config_for_blocking = Arc::clone(&config_c);
-        let (all_wallpapers, current_wallpaper) = gio::spawn_blocking(move || {
-            let wallpapers_path = quickshell::get_wallpapers_path();
-            let all_wallpapers = find_wallpapers(&wallpapers_path);
-            let current_wallpaper = config_for_blocking.lock().unwrap().last_wallpaper.clone();
-            (all_wallpapers, current_wallpaper)
-        })
-        .await
-        .expect("spawn_blocking");
-        
-        for (i, wallpaper_path) in all_wallpapers.iter().enumerate() {
-            let is_selected = current_wallpaper.as_ref().map(|w| w == wallpaper_path.to_string_lossy().as_ref()).unwrap_or(false);
-            let tile = create_wallpaper_tile(wallpaper_path, is_selected, Arc::clone(&config_c));
-            if i < 15 { flowbox_c.append(&tile); }
-            expanded_c.append(&tile);
-        }
-    });
+    let filter_dropdown = gtk4::DropDown::from_strings(&["All Wallpapers"]);
+    let grid = WallpaperGrid {
+        flowbox: flowbox.clone(),
+        expanded: expanded_flowbox.clone(),
+        config: Arc::clone(&config),
+        monitors: Rc::new(RefCell::new(Vec::new())),
+        debounce: Arc::new(Mutex::new(None)),
+        wallpapers: Rc::new(RefCell::new(Vec::new())),
+        filter: Rc::new(RefCell::new(WallpaperFilter::All)),
+        filter_options: Rc::new(RefCell::new(vec![WallpaperFilter::All])),
+        filter_dropdown: filter_dropdown.clone(),
+        updating_filters: Rc::new(std::cell::Cell::new(false)),
+        search: Rc::new(RefCell::new(SearchCriteria::default())),
+        generation: Rc::new(std::cell::Cell::new(0)),
+    };
+    {
+        let grid = grid.clone();
+        filter_dropdown.connect_selected_notify(move |d| {
+            if grid.updating_filters.get() {
+                return;
+            }
+            let selected = grid.filter_options.borrow().get(d.selected() as usize).cloned().unwrap_or_default();
+            *grid.filter.borrow_mut() = selected;
+            grid.refill();
+        });
+    }
+    grid.reload();
+
+    // Favorites, tags (user tags and subfolder categories) and collections
+    let filter_row = GtkBox::new(Orientation::Horizontal, 12);
+    let filter_label = Label::new(Some("Show"));
+    filter_label.add_css_class("row-description");
+    filter_row.append(&filter_label);
+    filter_row.append(&filter_dropdown);
+    let filter_hint = Label::new(Some("Right-click a wallpaper to favorite, tag or collect it"));
+    filter_hint.add_css_class("row-description");
+    filter_hint.set_hexpand(true);
+    filter_hint.set_halign(gtk4::Align::End);
+    filter_hint.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+    filter_row.append(&filter_hint);
+    grid_container.append(&filter_row);
+    grid_container.append(&create_wallpaper_search(&grid));
 
     grid_container.append(&flowbox);
 
@@ -721,11 +1252,1022 @@ fn create_background_section(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
     grid_container.append(&expanded_flowbox);
     grid_container.append(&show_more_button);
 
+    // Images dropped from a file manager are imported into the wallpaper folder
+    let drop_target = gtk4::DropTarget::new(gdk::FileList::static_type(), gdk::DragAction::COPY);
+    {
+        let grid = grid.clone();
+        drop_target.connect_drop(move |_, value, _, _| {
+            let Ok(files) = value.get::<gdk::FileList>() else {
+                return false;
+            };
+            let paths: Vec<PathBuf> = files.files().iter().filter_map(|f| f.path()).collect();
+            if paths.is_empty() {
+                return false;
+            }
+            import_wallpapers(paths, &grid);
+            true
+        });
+    }
+    grid_container.add_controller(drop_target);
+
     section.append(&grid_container);
+    section.append(&create_wallpaper_options(grid.clone()));
+    section.append(&create_wallpaper_sources(grid));
+    section
+}
+
+/// Slideshow on/off, interval, order, source folder and playback controls.
+fn create_slideshow_section(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let section = GtkBox::new(Orientation::Vertical, 0);
+    let current = config.lock().unwrap().clone();
+
+    // Playback controls, only usable while the slideshow process runs
+    let controls = GtkBox::new(Orientation::Horizontal, 6);
+    let back_button = Button::from_icon_name("media-skip-backward-symbolic");
+    back_button.set_tooltip_text(Some("Previous wallpaper"));
+    let pause_button = Button::from_icon_name(if slideshow::is_paused() { "media-playback-start-symbolic" } else { "media-playback-pause-symbolic" });
+    pause_button.set_tooltip_text(Some("Pause / resume"));
+    let skip_button = Button::from_icon_name("media-skip-forward-symbolic");
+    skip_button.set_tooltip_text(Some("Next wallpaper"));
+    for button in [&back_button, &pause_button, &skip_button] {
+        button.add_css_class("flat");
+        controls.append(button);
+    }
+    controls.set_sensitive(current.slideshow_enabled.unwrap_or(false));
+
+    back_button.connect_clicked(|_| {
+        let _ = slideshow::send_command(SlideshowCommand::Previous);
+    });
+    skip_button.connect_clicked(|_| {
+        let _ = slideshow::send_command(SlideshowCommand::Next);
+    });
+    pause_button.connect_clicked(|b| {
+        // The process flips its paused flag within a second; update the icon right away
+        let pause = !slideshow::is_paused();
+        let command = if pause { SlideshowCommand::Pause } else { SlideshowCommand::Resume };
+        if slideshow::send_command(command).is_ok() {
+            b.set_icon_name(if pause { "media-playback-start-symbolic" } else { "media-playback-pause-symbolic" });
+        }
+    });
+
+    let enable_switch = Switch::new();
+    enable_switch.set_active(current.slideshow_enabled.unwrap_or(false));
+    enable_switch.set_valign(gtk4::Align::Center);
+    {
+        let config = Arc::clone(&config);
+        let controls = controls.clone();
+        let pause_button = pause_button.clone();
+        enable_switch.connect_active_notify(move |s| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_slideshow_enabled(s.is_active());
+            if cfg.save().is_ok() {
+                *config.lock().unwrap() = cfg.clone();
+                // Disabling is picked up by the running process itself
+                if s.is_active() {
+                    let _ = slideshow::spawn_daemon();
+                }
+                controls.set_sensitive(s.is_active());
+                pause_button.set_icon_name("media-playback-pause-symbolic");
+            }
+        });
+    }
+    section.append(&create_card_row("Rotate Wallpapers", enable_switch));
+
+    let interval_spin = gtk4::SpinButton::with_range(1.0, 1440.0, 5.0);
+    interval_spin.set_value(current.slideshow_interval.unwrap_or(slideshow::DEFAULT_INTERVAL_MINUTES) as f64);
+    interval_spin.set_valign(gtk4::Align::Center);
+    interval_spin.set_tooltip_text(Some("Minutes"));
+    {
+        let config = Arc::clone(&config);
+        interval_spin.connect_value_changed(move |s| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_slideshow_interval(s.value() as u32);
+            if cfg.save().is_ok() {
+                *config.lock().unwrap() = cfg.clone();
+            }
+        });
+    }
+    section.append(&create_card_row("Interval (minutes)", interval_spin));
+
+    // Order
+    let order_box = GtkBox::new(Orientation::Horizontal, 6);
+    let current_order = current.slideshow_order.clone().unwrap_or_else(|| "shuffle".to_string());
+    let mut order_buttons = Vec::new();
+    for (label, value) in [("Shuffle", "shuffle"), ("In Order", "ordered")] {
+        let btn = Button::with_label(label);
+        if current_order == value {
+            btn.add_css_class("suggested-action");
+        }
+        order_buttons.push((btn.clone(), value.to_string()));
+        order_box.append(&btn);
+    }
+    for (btn, value) in order_buttons.clone() {
+        let config = Arc::clone(&config);
+        let buttons_clone = order_buttons.clone();
+        btn.connect_clicked(move |_| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_slideshow_order(&value);
+            if cfg.save().is_ok() {
+                *config.lock().unwrap() = cfg.clone();
+                for (b, v) in buttons_clone.iter() {
+                    if v == &value { b.add_css_class("suggested-action"); }
+                    else { b.remove_css_class("suggested-action"); }
+                }
+            }
+        });
+    }
+    section.append(&create_card_row("Order", order_box));
+
+    // Source folder or collection
+    let source_box = GtkBox::new(Orientation::Horizontal, 6);
+    let source_label = Label::new(Some(&slideshow::source_label(&current)));
+    source_label.add_css_class("row-description");
+    source_label.set_ellipsize(gtk4::pango::EllipsizeMode::Middle);
+    source_label.set_max_width_chars(32);
+    source_box.append(&source_label);
+
+    let choose_button = Button::from_icon_name("folder-open-symbolic");
+    choose_button.add_css_class("flat");
+    choose_button.set_tooltip_text(Some("Choose folder"));
+    source_box.append(&choose_button);
+
+    let collection_button = Button::from_icon_name("view-list-symbolic");
+    collection_button.add_css_class("flat");
+    collection_button.set_tooltip_text(Some("Choose collection"));
+    source_box.append(&collection_button);
+
+    let reset_button = Button::from_icon_name("edit-undo-symbolic");
+    reset_button.add_css_class("flat");
+    reset_button.set_tooltip_text(Some("Use all wallpaper folders"));
+    reset_button.set_sensitive(current.slideshow_source.is_some());
+    source_box.append(&reset_button);
+
+    {
+        let config = Arc::clone(&config);
+        let source_label = source_label.clone();
+        let reset_button = reset_button.clone();
+        choose_button.connect_clicked(move |button| {
+            let dialog = gtk4::FileDialog::new();
+            dialog.set_title("Slideshow Folder");
+            let window = button.root().and_downcast::<gtk4::Window>();
+            let config = Arc::clone(&config);
+            let source_label = source_label.clone();
+            let reset_button = reset_button.clone();
+            dialog.select_folder(window.as_ref(), gio::Cancellable::NONE, move |result| {
+                let Some(path) = result.ok().and_then(|folder| folder.path()) else {
+                    return;
+                };
+                let path = path.to_string_lossy().to_string();
+                let mut cfg = ColorConfig::load();
+                cfg.set_slideshow_source(Some(&path));
+                if cfg.save().is_ok() {
+                    *config.lock().unwrap() = cfg.clone();
+                    source_label.set_text(&path);
+                    reset_button.set_sensitive(true);
+                }
+            });
+        });
+    }
+    {
+        let config = Arc::clone(&config);
+        let source_label = source_label.clone();
+        let reset_button = reset_button.clone();
+        collection_button.connect_clicked(move |button| {
+            show_slideshow_collection_popover(button.upcast_ref(), &config, &source_label, &reset_button);
+        });
+    }
+    {
+        let config = Arc::clone(&config);
+        reset_button.connect_clicked(move |b| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_slideshow_source(None);
+            if cfg.save().is_ok() {
+                *config.lock().unwrap() = cfg.clone();
+                source_label.set_text("All wallpaper folders");
+                b.set_sensitive(false);
+            }
+        });
+    }
+    section.append(&create_card_row("Source", source_box));
+
+    section.append(&create_card_row("Playback", controls));
+
     section
 }
 
-fn create_wallpaper_tile(path: &PathBuf, is_selected: bool, config: Arc<Mutex<ColorConfig>>) -> Button {
+/// Popover listing the wallpaper collections; picking one makes it the slideshow source.
+fn show_slideshow_collection_popover(parent: &gtk4::Widget, config: &Arc<Mutex<ColorConfig>>, source_label: &Label, reset_button: &Button) {
+    let content = GtkBox::new(Orientation::Vertical, 2);
+    content.set_margin_start(6);
+    content.set_margin_end(6);
+    content.set_margin_top(6);
+    content.set_margin_bottom(6);
+
+    let popover = gtk4::Popover::new();
+    let collections: Vec<String> = WallpaperMeta::load().collections.into_keys().collect();
+    if collections.is_empty() {
+        let empty = Label::new(Some("No collections yet - add wallpapers to one from their right-click menu"));
+        empty.add_css_class("dim-label");
+        empty.set_wrap(true);
+        empty.set_max_width_chars(30);
+        content.append(&empty);
+    }
+    for name in collections {
+        let btn = Button::with_label(&name);
+        btn.add_css_class("flat");
+        let config = Arc::clone(config);
+        let source_label = source_label.clone();
+        let reset_button = reset_button.clone();
+        let popover = popover.clone();
+        btn.connect_clicked(move |_| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_slideshow_source(Some(&format!("{}{}", slideshow::COLLECTION_PREFIX, name)));
+            if cfg.save().is_ok() {
+                *config.lock().unwrap() = cfg.clone();
+                source_label.set_text(&slideshow::source_label(&cfg));
+                reset_button.set_sensitive(true);
+            }
+            popover.popdown();
+        });
+        content.append(&btn);
+    }
+
+    popover.set_child(Some(&content));
+    popover.set_parent(parent);
+    popover.connect_closed(|popover| popover.unparent());
+    popover.popup();
+}
+
+/// Filename search plus resolution, aspect ratio, orientation and color filters.
+fn create_wallpaper_search(grid: &WallpaperGrid) -> GtkBox {
+    let container = GtkBox::new(Orientation::Vertical, 8);
+
+    let search_entry = gtk4::SearchEntry::new();
+    search_entry.set_placeholder_text(Some("Search wallpapers by name"));
+    search_entry.set_hexpand(true);
+    {
+        let grid = grid.clone();
+        search_entry.connect_search_changed(move |e| {
+            grid.search.borrow_mut().text = e.text().to_string();
+            grid.refill();
+        });
+    }
+    container.append(&search_entry);
+
+    let filters = GtkBox::new(Orientation::Horizontal, 6);
+
+    let resolutions: [(&str, u32); 4] = [("Any Resolution", 0), ("1080p+", 1080), ("1440p+", 1440), ("4K+", 2160)];
+    let resolution_labels: Vec<&str> = resolutions.iter().map(|(label, _)| *label).collect();
+    let resolution_dropdown = gtk4::DropDown::from_strings(&resolution_labels);
+    {
+        let grid = grid.clone();
+        resolution_dropdown.connect_selected_notify(move |d| {
+            grid.search.borrow_mut().min_height = resolutions.get(d.selected() as usize).map(|(_, h)| *h).unwrap_or(0);
+            grid.refill();
+        });
+    }
+    filters.append(&resolution_dropdown);
+
+    let aspect_dropdown = gtk4::DropDown::from_strings(&["Any Aspect", "Match Monitor"]);
+    {
+        let grid = grid.clone();
+        aspect_dropdown.connect_selected_notify(move |d| {
+            grid.search.borrow_mut().aspect = if d.selected() == 1 { monitor_aspect_ratio() } else { None };
+            grid.refill();
+        });
+    }
+    filters.append(&aspect_dropdown);
+
+    let orientations = [
+        ("Any Orientation", None),
+        ("Landscape", Some(wallpapers::Orientation::Landscape)),
+        ("Portrait", Some(wallpapers::Orientation::Portrait)),
+        ("Square", Some(wallpapers::Orientation::Square)),
+    ];
+    let orientation_labels: Vec<&str> = orientations.iter().map(|(label, _)| *label).collect();
+    let orientation_dropdown = gtk4::DropDown::from_strings(&orientation_labels);
+    {
+        let grid = grid.clone();
+        orientation_dropdown.connect_selected_notify(move |d| {
+            grid.search.borrow_mut().orientation = orientations.get(d.selected() as usize).and_then(|(_, o)| *o);
+            grid.refill();
+        });
+    }
+    filters.append(&orientation_dropdown);
+
+    let mut color_labels = vec!["Any Color"];
+    color_labels.extend(wallpapers::HUE_FAMILIES.iter().map(|(_, label)| *label));
+    let color_dropdown = gtk4::DropDown::from_strings(&color_labels);
+    {
+        let grid = grid.clone();
+        color_dropdown.connect_selected_notify(move |d| {
+            let selected = d.selected() as usize;
+            grid.search.borrow_mut().hue = selected.checked_sub(1).and_then(|i| wallpapers::HUE_FAMILIES.get(i)).map(|(hue, _)| *hue);
+            grid.refill();
+        });
+    }
+    filters.append(&color_dropdown);
+
+    container.append(&filters);
+    container
+}
+
+/// Width / height of the primary output (Hyprland's first monitor, else GDK's).
+fn monitor_aspect_ratio() -> Option<f64> {
+    if let Some(monitor) = monitors::list_monitors().into_iter().find(|m| m.width > 0 && m.height > 0) {
+        return Some(monitor.width as f64 / monitor.height as f64);
+    }
+    let display = gdk::Display::default()?;
+    let monitor = display.monitors().item(0).and_downcast::<gdk::Monitor>()?;
+    let geometry = monitor.geometry();
+    (geometry.height() > 0).then(|| geometry.width() as f64 / geometry.height() as f64)
+}
+
+/// Popover for editing a wallpaper's favorite flag, tags and collections. Saved when it closes.
+fn show_wallpaper_meta_popover(parent: &gtk4::Widget, path: &std::path::Path, grid: &WallpaperGrid) {
+    let key = path.to_string_lossy().to_string();
+    let meta = WallpaperMeta::load();
+
+    let content = GtkBox::new(Orientation::Vertical, 8);
+    content.set_margin_start(12);
+    content.set_margin_end(12);
+    content.set_margin_top(12);
+    content.set_margin_bottom(12);
+
+    let favorite = gtk4::CheckButton::with_label("Favorite");
+    favorite.set_active(meta.is_favorite(&key));
+    content.append(&favorite);
+
+    let tags_label = Label::new(Some("Tags (comma separated)"));
+    tags_label.add_css_class("row-description");
+    tags_label.set_halign(gtk4::Align::Start);
+    content.append(&tags_label);
+    let tags_entry = Entry::new();
+    tags_entry.set_text(&meta.tags_of(&key).join(", "));
+    let categories = wallpaper_meta::categories(path, &wallpapers::source_dirs(&grid.config.lock().unwrap()));
+    if !categories.is_empty() {
+        tags_entry.set_placeholder_text(Some(&format!("Folder: {}", categories.join(", "))));
+    }
+    content.append(&tags_entry);
+
+    let collections_label = Label::new(Some("Collections (comma separated)"));
+    collections_label.add_css_class("row-description");
+    collections_label.set_halign(gtk4::Align::Start);
+    content.append(&collections_label);
+    let collections_entry = Entry::new();
+    collections_entry.set_text(&meta.collections_of(&key).join(", "));
+    if !meta.collections.is_empty() {
+        let existing: Vec<&str> = meta.collections.keys().map(|n| n.as_str()).collect();
+        collections_entry.set_placeholder_text(Some(&existing.join(", ")));
+    }
+    content.append(&collections_entry);
+
+    let popover = gtk4::Popover::new();
+    popover.set_child(Some(&content));
+    popover.set_parent(parent);
+
+    let grid = grid.clone();
+    popover.connect_closed(move |popover| {
+        let split = |text: &str| -> Vec<String> { text.split(',').map(|s| s.trim().to_string()).collect() };
+        let mut meta = WallpaperMeta::load();
+        meta.set_favorite(&key, favorite.is_active());
+        meta.set_tags(&key, &split(&tags_entry.text()));
+        meta.set_collections(&key, &split(&collections_entry.text()));
+        popover.unparent();
+        if meta.save().is_ok() {
+            // Rebuilding the tiles destroys the one the popover belonged to - do it afterwards
+            let grid = grid.clone();
+            gtk4::glib::idle_add_local_once(move || grid.refill());
+        }
+    });
+    popover.popup();
+}
+
+/// Row of monitor cards, each showing that output's wallpaper and accepting dropped wallpapers.
+fn create_monitor_strip(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let strip = GtkBox::new(Orientation::Horizontal, 12);
+    strip.set_halign(gtk4::Align::Center);
+
+    let strip_c = strip.clone();
+    gtk4::glib::MainContext::default().spawn_local(async move {
+        let mut outputs = gio::spawn_blocking(monitors::list_monitors).await.unwrap_or_default();
+
+        // Not on Hyprland: GDK still knows the connectors
+        if outputs.is_empty() {
+            if let Some(display) = gdk::Display::default() {
+                let list = display.monitors();
+                for i in 0..list.n_items() {
+                    let Some(gdk_monitor) = list.item(i).and_downcast::<gdk::Monitor>() else {
+                        continue;
+                    };
+                    let Some(connector) = gdk_monitor.connector() else {
+                        continue;
+                    };
+                    let geometry = gdk_monitor.geometry();
+                    outputs.push(monitors::Monitor {
+                        name: connector.to_string(),
+                        description: gdk_monitor.model().map(|m| m.to_string()).unwrap_or_default(),
+                        width: geometry.width(),
+                        height: geometry.height(),
+                        x: geometry.x(),
+                        y: geometry.y(),
+                    });
+                }
+            }
+        }
+
+        // A single output just uses the main wallpaper
+        if outputs.len() < 2 {
+            strip_c.set_visible(false);
+            return;
+        }
+        for output in &outputs {
+            strip_c.append(&create_monitor_card(output, Arc::clone(&config)));
+        }
+    });
+
+    strip
+}
+
+fn create_monitor_card(output: &monitors::Monitor, config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let card = GtkBox::new(Orientation::Vertical, 4);
+    card.add_css_class("monitor-card");
+    if !output.description.is_empty() {
+        card.set_tooltip_text(Some(&output.description));
+    }
+
+    // Screen shape, scaled to a fixed width
+    let width = 180;
+    let height = if output.width > 0 { width * output.height / output.width } else { width * 9 / 16 };
+
+    let overlay = Overlay::new();
+    overlay.set_size_request(width, height);
+    let picture = Picture::new();
+    picture.set_content_fit(content_fit_for(config.lock().unwrap().wallpaper_fit.as_deref()));
+    picture.set_can_shrink(true);
+    overlay.set_child(Some(&picture));
+
+    let placeholder = Label::new(Some("Drop a wallpaper here"));
+    placeholder.add_css_class("wallpaper-placeholder");
+    placeholder.set_wrap(true);
+    overlay.add_overlay(&placeholder);
+    card.append(&overlay);
+
+    let current = config.lock().unwrap().clone();
+    let has_own = current.monitor_wallpapers.as_ref().map(|w| w.contains_key(&output.name)).unwrap_or(false);
+    if let Some(wallpaper) = current.wallpaper_for(&output.name) {
+        wallpaper_tile::load_thumbnail(&picture, &placeholder, std::path::Path::new(&wallpaper));
+    }
+
+    let footer = GtkBox::new(Orientation::Horizontal, 6);
+    let name = Label::new(Some(&format!("{}  •  {}×{}", output.name, output.width, output.height)));
+    name.add_css_class("row-description");
+    name.set_hexpand(true);
+    name.set_halign(gtk4::Align::Start);
+    footer.append(&name);
+
+    let clear_button = Button::from_icon_name("edit-undo-symbolic");
+    clear_button.add_css_class("flat");
+    clear_button.set_tooltip_text(Some("Use the main wallpaper"));
+    clear_button.set_sensitive(has_own);
+    footer.append(&clear_button);
+    card.append(&footer);
+
+    let drop_target = gtk4::DropTarget::new(gio::File::static_type(), gdk::DragAction::COPY);
+    {
+        let output_name = output.name.clone();
+        let config = Arc::clone(&config);
+        let picture = picture.clone();
+        let placeholder = placeholder.clone();
+        let clear_button = clear_button.clone();
+        drop_target.connect_drop(move |_, value, _, _| {
+            let Some(path) = value.get::<gio::File>().ok().and_then(|file| file.path()) else {
+                return false;
+            };
+            if !wallpapers::is_wallpaper_file(&path) {
+                return false;
+            }
+            let output_name = output_name.clone();
+            let config = Arc::clone(&config);
+            let picture = picture.clone();
+            let placeholder = placeholder.clone();
+            let clear_button = clear_button.clone();
+            gtk4::glib::MainContext::default().spawn_local(async move {
+                let wallpaper = path.to_string_lossy().to_string();
+                let result = gio::spawn_blocking(move || {
+                    quickshell::set_monitor_wallpaper(&output_name, Some(&wallpaper)).map_err(|e| e.to_string())
+                })
+                .await;
+                if let Ok(Ok(())) = result {
+                    *config.lock().unwrap() = ColorConfig::load();
+                    wallpaper_tile::load_thumbnail(&picture, &placeholder, &path);
+                    clear_button.set_sensitive(true);
+                }
+            });
+            true
+        });
+    }
+    card.add_controller(drop_target);
+
+    let output_name = output.name.clone();
+    clear_button.connect_clicked(move |b| {
+        let output_name = output_name.clone();
+        let config = Arc::clone(&config);
+        let picture = picture.clone();
+        let placeholder = placeholder.clone();
+        let b = b.clone();
+        gtk4::glib::MainContext::default().spawn_local(async move {
+            let output = output_name.clone();
+            let result = gio::spawn_blocking(move || {
+                quickshell::set_monitor_wallpaper(&output, None).map_err(|e| e.to_string())
+            })
+            .await;
+            if !matches!(result, Ok(Ok(()))) {
+                return;
+            }
+            let cfg = ColorConfig::load();
+            match cfg.wallpaper_for(&output_name) {
+                Some(wallpaper) => wallpaper_tile::load_thumbnail(&picture, &placeholder, std::path::Path::new(&wallpaper)),
+                None => {
+                    picture.set_paintable(gdk::Paintable::NONE);
+                    placeholder.set_visible(true);
+                }
+            }
+            *config.lock().unwrap() = cfg;
+            b.set_sensitive(false);
+        });
+    });
+
+    card
+}
+
+/// The wallpaper grids plus the folder monitors that keep them current.
+#[derive(Clone)]
+struct WallpaperGrid {
+    flowbox: FlowBox,
+    expanded: FlowBox,
+    config: Arc<Mutex<ColorConfig>>,
+    monitors: Rc<RefCell<Vec<gio::FileMonitor>>>,
+    debounce: Arc<Mutex<Option<gtk4::glib::SourceId>>>,
+    /// Result of the last folder scan, before filtering
+    wallpapers: Rc<RefCell<Vec<PathBuf>>>,
+    filter: Rc<RefCell<WallpaperFilter>>,
+    /// Filter behind each dropdown entry
+    filter_options: Rc<RefCell<Vec<WallpaperFilter>>>,
+    filter_dropdown: gtk4::DropDown,
+    /// Set while the dropdown model is rebuilt, so that doesn't count as a user choice
+    updating_filters: Rc<std::cell::Cell<bool>>,
+    search: Rc<RefCell<SearchCriteria>>,
+    /// Bumped on every refill; a slower, older refill must not overwrite a newer one
+    generation: Rc<std::cell::Cell<u32>>,
+}
+
+impl WallpaperGrid {
+    /// Rescan the wallpaper folders, rebuild the tiles and re-watch the folders
+    /// (subdirectories may have come or gone).
+    fn reload(&self) {
+        let grid = self.clone();
+        gtk4::glib::MainContext::default().spawn_local(async move {
+            let config_for_blocking = grid.config.lock().unwrap().clone();
+            let (all_wallpapers, watched_dirs) = gio::spawn_blocking(move || {
+                (wallpapers::find_wallpapers(&config_for_blocking), wallpapers::watched_dirs(&config_for_blocking))
+            })
+            .await
+            .expect("spawn_blocking");
+            *grid.wallpapers.borrow_mut() = all_wallpapers;
+            grid.refill();
+            grid.watch(&watched_dirs);
+        });
+    }
+
+    /// Rebuild the tiles from the last scan with the current filter, and refresh the filter list.
+    fn refill(&self) {
+        let meta = WallpaperMeta::load();
+        let config = self.config.lock().unwrap().clone();
+        let dirs = wallpapers::source_dirs(&config);
+        let all_wallpapers = self.wallpapers.borrow().clone();
+        self.update_filter_options(&meta, &all_wallpapers, &dirs);
+
+        let filter = self.filter.borrow().clone();
+        let criteria = self.search.borrow().clone();
+        let candidates: Vec<PathBuf> = all_wallpapers
+            .into_iter()
+            .filter(|p| meta.matches(p, &dirs, &filter) && criteria.matches_name(p))
+            .collect();
+        let filtered = filter != WallpaperFilter::All || criteria != SearchCriteria::default();
+
+        let generation = self.generation.get().wrapping_add(1);
+        self.generation.set(generation);
+        let grid = self.clone();
+        gtk4::glib::MainContext::default().spawn_local(async move {
+            // Size and color come from the thumbnail cache, filling it in where needed
+            let shown = if criteria.needs_image_info() {
+                gio::spawn_blocking(move || {
+                    candidates
+                        .into_iter()
+                        .filter(|p| thumbnails::image_info(p).map(|info| criteria.matches_info(&info)).unwrap_or(false))
+                        .collect::<Vec<_>>()
+                })
+                .await
+                .unwrap_or_default()
+            } else {
+                candidates
+            };
+            if grid.generation.get() != generation {
+                return;
+            }
+
+            for flowbox in [&grid.flowbox, &grid.expanded] {
+                while let Some(child) = flowbox.first_child() {
+                    flowbox.remove(&child);
+                }
+            }
+            if shown.is_empty() && filtered {
+                let empty = Label::new(Some("No wallpapers match this filter"));
+                empty.add_css_class("dim-label");
+                grid.flowbox.append(&empty);
+            }
+            for (i, wallpaper_path) in shown.iter().enumerate() {
+                let key = wallpaper_path.to_string_lossy();
+                let is_selected = config.last_wallpaper.as_deref() == Some(key.as_ref());
+                let tile = create_wallpaper_tile(wallpaper_path, is_selected, meta.is_favorite(&key), &grid);
+                if i < 15 { grid.flowbox.append(&tile); }
+                grid.expanded.append(&tile);
+            }
+        });
+    }
+
+    fn update_filter_options(&self, meta: &WallpaperMeta, all_wallpapers: &[PathBuf], dirs: &[PathBuf]) {
+        let mut options = vec![WallpaperFilter::All, WallpaperFilter::Favorites];
+        options.extend(meta.collections.keys().map(|name| WallpaperFilter::Collection(name.clone())));
+        options.extend(meta.all_tags(all_wallpapers, dirs).into_iter().map(WallpaperFilter::Tag));
+
+        let labels: Vec<String> = options
+            .iter()
+            .map(|option| match option {
+                WallpaperFilter::All => "All Wallpapers".to_string(),
+                WallpaperFilter::Favorites => "★ Favorites".to_string(),
+                WallpaperFilter::Collection(name) => format!("Collection: {}", name),
+                WallpaperFilter::Tag(tag) => format!("#{}", tag),
+            })
+            .collect();
+        let labels: Vec<&str> = labels.iter().map(|l| l.as_str()).collect();
+
+        // A filter whose tag or collection disappeared falls back to everything
+        let current = self.filter.borrow().clone();
+        let selected = options.iter().position(|o| *o == current).unwrap_or(0);
+        if selected == 0 {
+            *self.filter.borrow_mut() = WallpaperFilter::All;
+        }
+
+        self.updating_filters.set(true);
+        self.filter_dropdown.set_model(Some(&gtk4::StringList::new(&labels)));
+        self.filter_dropdown.set_selected(selected as u32);
+        self.updating_filters.set(false);
+        *self.filter_options.borrow_mut() = options;
+    }
+
+    fn watch(&self, dirs: &[PathBuf]) {
+        for monitor in self.monitors.borrow_mut().drain(..) {
+            monitor.cancel();
+        }
+
+        for dir in dirs {
+            let file = gio::File::for_path(dir);
+            if let Ok(monitor) = file.monitor_directory(gio::FileMonitorFlags::NONE, gio::Cancellable::NONE) {
+                let grid = self.clone();
+                monitor.connect_changed(move |_, _, _, event_type| {
+                    if matches!(
+                        event_type,
+                        gio::FileMonitorEvent::Created | gio::FileMonitorEvent::Deleted | gio::FileMonitorEvent::ChangesDoneHint
+                    ) {
+                        grid.schedule_reload();
+                    }
+                });
+                self.monitors.borrow_mut().push(monitor);
+            }
+        }
+    }
+
+    /// Copying a folder of wallpapers fires many events - rescan once things settle.
+    fn schedule_reload(&self) {
+        let mut db_id = self.debounce.lock().unwrap();
+        if let Some(id) = db_id.take() {
+            id.remove();
+        }
+        let grid = self.clone();
+        *db_id = Some(gtk4::glib::timeout_add_local(std::time::Duration::from_millis(500), move || {
+            grid.debounce.lock().unwrap().take();
+            grid.reload();
+            gtk4::glib::ControlFlow::Break
+        }));
+    }
+}
+
+/// Closest GTK equivalent of a wallpaper fit mode, for previews. GTK can't tile, so tiles fill.
+fn content_fit_for(fit: Option<&str>) -> gtk4::ContentFit {
+    match fit.unwrap_or(backgrounds::DEFAULT_FIT) {
+        "fit" => gtk4::ContentFit::Contain,
+        "center" => gtk4::ContentFit::ScaleDown,
+        "stretch" => gtk4::ContentFit::Fill,
+        _ => gtk4::ContentFit::Cover,
+    }
+}
+
+/// Fit mode and palette-generated solid / gradient backgrounds.
+fn create_wallpaper_options(grid: WallpaperGrid) -> GtkBox {
+    let section = GtkBox::new(Orientation::Vertical, 0);
+
+    // Fit mode
+    let fit_box = GtkBox::new(Orientation::Horizontal, 6);
+    let current_fit = grid.config.lock().unwrap().wallpaper_fit.clone().unwrap_or_else(|| backgrounds::DEFAULT_FIT.to_string());
+    let mut fit_buttons = Vec::new();
+    for (value, label) in backgrounds::FIT_MODES {
+        let btn = Button::with_label(label);
+        if current_fit == *value {
+            btn.add_css_class("suggested-action");
+        }
+        fit_buttons.push((btn.clone(), value.to_string()));
+        fit_box.append(&btn);
+    }
+    for (btn, value) in fit_buttons.clone() {
+        let grid = grid.clone();
+        let buttons_clone = fit_buttons.clone();
+        btn.connect_clicked(move |_| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_wallpaper_fit(&value);
+            if cfg.save().is_ok() {
+                *grid.config.lock().unwrap() = cfg.clone();
+                for (b, v) in buttons_clone.iter() {
+                    if v == &value { b.add_css_class("suggested-action"); }
+                    else { b.remove_css_class("suggested-action"); }
+                }
+                // Tiles preview the fit mode
+                grid.reload();
+                schedule_notify_color_change_ms(200);
+            }
+        });
+    }
+    section.append(&create_card_row("Fit", fit_box));
+
+    // Generated from the current palette
+    let generated_box = GtkBox::new(Orientation::Horizontal, 6);
+    let generated: [(&str, &str, Option<&str>); 4] = [
+        ("Solid", "background", None),
+        ("Accent", "accent", None),
+        ("Gradient", "background", Some("accent")),
+        ("Soft Gradient", "primary", Some("secondary")),
+    ];
+    for (label, from, to) in generated {
+        let btn = Button::with_label(label);
+        let grid = grid.clone();
+        btn.connect_clicked(move |_| {
+            let cfg = ColorConfig::load();
+            let from = cfg.global_color(from);
+            let to = to.map(|role| cfg.global_color(role));
+            let grid = grid.clone();
+            gtk4::glib::MainContext::default().spawn_local(async move {
+                // A 4K image takes a moment to encode
+                let result = gio::spawn_blocking(move || {
+                    let path = match to {
+                        Some(to) => backgrounds::generate_gradient(&from, &to),
+                        None => backgrounds::generate_solid(&from),
+                    }
+                    .map_err(|e| e.to_string())?;
+                    quickshell::set_wallpaper(&path.to_string_lossy()).map_err(|e| e.to_string())
+                })
+                .await;
+                if let Ok(Ok(())) = result {
+                    *grid.config.lock().unwrap() = ColorConfig::load();
+                    grid.reload();
+                }
+            });
+        });
+        generated_box.append(&btn);
+    }
+    section.append(&create_card_row("From Palette", generated_box));
+
+    section
+}
+
+/// Copy or link `paths` into the wallpaper folder, then show them in the grid.
+fn import_wallpapers(paths: Vec<PathBuf>, grid: &WallpaperGrid) {
+    let config = grid.config.lock().unwrap().clone();
+    let grid = grid.clone();
+    gtk4::glib::MainContext::default().spawn_local(async move {
+        let imported = gio::spawn_blocking(move || {
+            paths
+                .iter()
+                .filter(|p| wallpapers::is_wallpaper_file(p))
+                .filter_map(|p| wallpapers::import_wallpaper(p, &config).ok())
+                .count()
+        })
+        .await
+        .unwrap_or(0);
+        if imported > 0 {
+            // A filter could hide the new tiles
+            *grid.filter.borrow_mut() = WallpaperFilter::All;
+            grid.reload();
+        }
+    });
+}
+
+/// Wallpaper folders (add/remove), scan depth and hidden-file toggle.
+fn create_wallpaper_sources(grid: WallpaperGrid) -> GtkBox {
+    let section = GtkBox::new(Orientation::Vertical, 0);
+
+    let folders_box = GtkBox::new(Orientation::Vertical, 0);
+    section.append(&folders_box);
+    refresh_wallpaper_folder_rows(&folders_box, &grid);
+
+    let add_button = Button::with_label("Add Folder");
+    add_button.add_css_class("flat");
+    {
+        let folders_box = folders_box.clone();
+        let grid = grid.clone();
+        add_button.connect_clicked(move |button| {
+            let dialog = gtk4::FileDialog::new();
+            dialog.set_title("Add Wallpaper Folder");
+            let window = button.root().and_downcast::<gtk4::Window>();
+            let folders_box = folders_box.clone();
+            let grid = grid.clone();
+            dialog.select_folder(window.as_ref(), gio::Cancellable::NONE, move |result| {
+                let Some(path) = result.ok().and_then(|folder| folder.path()) else {
+                    return;
+                };
+                let mut cfg = ColorConfig::load();
+                let mut dirs: Vec<String> = wallpapers::source_dirs(&cfg).iter().map(|d| d.to_string_lossy().to_string()).collect();
+                let path = path.to_string_lossy().to_string();
+                if dirs.contains(&path) {
+                    return;
+                }
+                dirs.push(path);
+                cfg.set_wallpaper_dirs(dirs);
+                if cfg.save().is_ok() {
+                    *grid.config.lock().unwrap() = cfg.clone();
+                    refresh_wallpaper_folder_rows(&folders_box, &grid);
+                    grid.reload();
+                }
+            });
+        });
+    }
+    section.append(&create_card_row("Wallpaper Folders", add_button));
+
+    let depth = grid.config.lock().unwrap().wallpaper_depth.unwrap_or(DEFAULT_WALLPAPER_DEPTH);
+    let depth_spin = gtk4::SpinButton::with_range(0.0, MAX_WALLPAPER_DEPTH as f64, 1.0);
+    depth_spin.set_value(depth as f64);
+    depth_spin.set_valign(gtk4::Align::Center);
+    {
+        let grid = grid.clone();
+        depth_spin.connect_value_changed(move |s| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_wallpaper_depth(s.value() as u8);
+            if cfg.save().is_ok() {
+                *grid.config.lock().unwrap() = cfg.clone();
+                grid.schedule_reload();
+            }
+        });
+    }
+    section.append(&create_card_row("Subfolder Depth", depth_spin));
+
+    let hidden_switch = Switch::new();
+    hidden_switch.set_active(grid.config.lock().unwrap().wallpaper_show_hidden.unwrap_or(false));
+    hidden_switch.set_valign(gtk4::Align::Center);
+    {
+        let grid = grid.clone();
+        hidden_switch.connect_active_notify(move |s| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_wallpaper_show_hidden(s.is_active());
+            if cfg.save().is_ok() {
+                *grid.config.lock().unwrap() = cfg.clone();
+                grid.reload();
+            }
+        });
+    }
+    section.append(&create_card_row("Include Hidden Files", hidden_switch));
+
+    // Import
+    let import_box = GtkBox::new(Orientation::Horizontal, 6);
+    let current_mode = grid.config.lock().unwrap().wallpaper_import_mode.clone().unwrap_or_else(|| "copy".to_string());
+    let mut mode_buttons = Vec::new();
+    for (label, value) in [("Copy", "copy"), ("Link", "link")] {
+        let btn = Button::with_label(label);
+        if current_mode == value {
+            btn.add_css_class("suggested-action");
+        }
+        mode_buttons.push((btn.clone(), value.to_string()));
+        import_box.append(&btn);
+    }
+    for (btn, value) in mode_buttons.clone() {
+        let grid = grid.clone();
+        let buttons_clone = mode_buttons.clone();
+        btn.connect_clicked(move |_| {
+            let mut cfg = ColorConfig::load();
+            cfg.set_wallpaper_import_mode(&value);
+            if cfg.save().is_ok() {
+                *grid.config.lock().unwrap() = cfg.clone();
+                for (b, v) in buttons_clone.iter() {
+                    if v == &value { b.add_css_class("suggested-action"); }
+                    else { b.remove_css_class("suggested-action"); }
+                }
+            }
+        });
+    }
+
+    let import_button = Button::with_label("Import…");
+    import_button.add_css_class("flat");
+    {
+        let grid = grid.clone();
+        import_button.connect_clicked(move |button| {
+            let filter = gtk4::FileFilter::new();
+            filter.set_name(Some("Images and Videos"));
+            filter.add_mime_type("image/*");
+            filter.add_mime_type("video/*");
+            let filters = gio::ListStore::new::<gtk4::FileFilter>();
+            filters.append(&filter);
+
+            let dialog = gtk4::FileDialog::new();
+            dialog.set_title("Import Wallpapers");
+            dialog.set_filters(Some(&filters));
+            let window = button.root().and_downcast::<gtk4::Window>();
+            let grid = grid.clone();
+            dialog.open_multiple(window.as_ref(), gio::Cancellable::NONE, move |result| {
+                let Ok(files) = result else {
+                    return;
+                };
+                let paths: Vec<PathBuf> = (0..files.n_items())
+                    .filter_map(|i| files.item(i).and_downcast::<gio::File>())
+                    .filter_map(|f| f.path())
+                    .collect();
+                import_wallpapers(paths, &grid);
+            });
+        });
+    }
+    import_box.append(&import_button);
+    section.append(&create_card_row("Import (or drop images on the grid)", import_box));
+
+    let duplicates_button = Button::with_label("Find Duplicates");
+    duplicates_button.add_css_class("flat");
+    duplicates_button.connect_clicked(move |button| {
+        let window = button.root().and_downcast::<gtk4::Window>();
+        let grid_after = grid.clone();
+        duplicates_dialog::show_duplicates_dialog(window.as_ref(), grid.wallpapers.borrow().clone(), move || grid_after.reload());
+    });
+    section.append(&create_card_row("Duplicate Wallpapers", duplicates_button));
+
+    section
+}
+
+fn refresh_wallpaper_folder_rows(folders_box: &GtkBox, grid: &WallpaperGrid) {
+    while let Some(child) = folders_box.first_child() {
+        folders_box.remove(&child);
+    }
+
+    let cfg = grid.config.lock().unwrap().clone();
+    // Without configured folders the default one is listed, but there's nothing to remove
+    let removable = cfg.wallpaper_dirs.as_ref().map(|d| !d.is_empty()).unwrap_or(false);
+    for dir in wallpapers::source_dirs(&cfg) {
+        let dir = dir.to_string_lossy().to_string();
+        let path_label = Label::new(Some(&dir));
+        path_label.add_css_class("row-description");
+        path_label.set_ellipsize(gtk4::pango::EllipsizeMode::Middle);
+        path_label.set_hexpand(true);
+        path_label.set_halign(gtk4::Align::Start);
+
+        let remove_button = Button::from_icon_name("list-remove-symbolic");
+        remove_button.add_css_class("flat");
+        remove_button.set_tooltip_text(Some("Remove folder"));
+        remove_button.set_sensitive(removable);
+
+        let row = GtkBox::new(Orientation::Horizontal, 12);
+        row.add_css_class("card-row");
+        row.set_valign(gtk4::Align::Center);
+        row.append(&path_label);
+        row.append(&remove_button);
+
+        let folders_box_c = folders_box.clone();
+        let grid = grid.clone();
+        remove_button.connect_clicked(move |_| {
+            let mut cfg = ColorConfig::load();
+            let dirs = wallpapers::source_dirs(&cfg)
+                .iter()
+                .map(|d| d.to_string_lossy().to_string())
+                .filter(|d| d != &dir)
+                .collect();
+            cfg.set_wallpaper_dirs(dirs);
+            if cfg.save().is_ok() {
+                *grid.config.lock().unwrap() = cfg.clone();
+                refresh_wallpaper_folder_rows(&folders_box_c, &grid);
+                grid.reload();
+            }
+        });
+
+        folders_box.append(&row);
+    }
+}
+
+fn create_wallpaper_tile(path: &PathBuf, is_selected: bool, is_favorite: bool, grid: &WallpaperGrid) -> Button {
+    let config = Arc::clone(&grid.config);
     let button = Button::new();
     button.add_css_class("wallpaper-tile-appearance");
     
@@ -736,16 +2278,49 @@ fn create_wallpaper_tile(path: &PathBuf, is_selected: bool, config: Arc<Mutex<Co
     // Use Overlay to add checkmark on selected tile
     let overlay = Overlay::new();
     
-    // Picture widget for wallpaper - responsive
+    // Picture widget for wallpaper - responsive; filled with a cached thumbnail, never the full image
     let picture = Picture::new();
-    let file = gtk4::gio::File::for_path(path.as_path());
-    picture.set_file(Some(&file));
-    picture.set_content_fit(gtk4::ContentFit::Cover);
+    picture.set_content_fit(content_fit_for(config.lock().unwrap().wallpaper_fit.as_deref()));
     picture.set_hexpand(true);
     picture.set_vexpand(true);
     picture.set_can_shrink(true);
     overlay.set_child(Some(&picture));
 
+    // Placeholder until the thumbnail is ready
+    let placeholder = GtkBox::new(Orientation::Vertical, 0);
+    placeholder.add_css_class("wallpaper-placeholder");
+    placeholder.set_hexpand(true);
+    placeholder.set_vexpand(true);
+    let placeholder_icon = gtk4::Image::from_icon_name("image-x-generic-symbolic");
+    placeholder_icon.set_pixel_size(32);
+    placeholder_icon.set_vexpand(true);
+    placeholder_icon.set_valign(gtk4::Align::Center);
+    placeholder.append(&placeholder_icon);
+    overlay.add_overlay(&placeholder);
+
+    wallpaper_tile::load_thumbnail(&picture, &placeholder, path);
+
+    // Animation / video marker, with playback on hover
+    let kind_badge = Label::new(None);
+    kind_badge.add_css_class("wallpaper-kind");
+    kind_badge.set_halign(gtk4::Align::End);
+    kind_badge.set_valign(gtk4::Align::Start);
+    kind_badge.set_margin_end(8);
+    kind_badge.set_margin_top(8);
+    wallpaper_tile::show_kind(&picture, &kind_badge, path);
+    overlay.add_overlay(&kind_badge);
+
+    // Drag onto a monitor card to use it on that output only
+    let drag_source = gtk4::DragSource::new();
+    drag_source.set_actions(gdk::DragAction::COPY);
+    drag_source.set_content(Some(&gdk::ContentProvider::for_value(&gio::File::for_path(path).to_value())));
+    drag_source.connect_drag_begin(move |source, _| {
+        if let Some(paintable) = picture.paintable() {
+            source.set_icon(Some(&paintable), 0, 0);
+        }
+    });
+    button.add_controller(drag_source);
+
     // Selected indicator (checkmark)
     if is_selected {
         let checkmark_container = GtkBox::new(Orientation::Horizontal, 0);
@@ -762,6 +2337,28 @@ fn create_wallpaper_tile(path: &PathBuf, is_selected: bool, config: Arc<Mutex<Co
         overlay.add_overlay(&checkmark_container);
     }
 
+    if is_favorite {
+        let star = Label::new(Some("★"));
+        star.add_css_class("wallpaper-favorite");
+        star.set_halign(gtk4::Align::Start);
+        star.set_valign(gtk4::Align::Start);
+        star.set_margin_start(8);
+        star.set_margin_top(6);
+        overlay.add_overlay(&star);
+    }
+
+    // Right click: favorite, tags and collections
+    let context_click = gtk4::GestureClick::new();
+    context_click.set_button(gdk::BUTTON_SECONDARY);
+    {
+        let path = path.clone();
+        let grid = grid.clone();
+        context_click.connect_pressed(move |gesture, _, _, _| {
+            show_wallpaper_meta_popover(&gesture.widget(), &path, &grid);
+        });
+    }
+    button.add_controller(context_click);
+
     button.set_child(Some(&overlay));
     button.set_hexpand(true);
     button.set_vexpand(true);
@@ -771,47 +2368,18 @@ fn create_wallpaper_tile(path: &PathBuf, is_selected: bool, config: Arc<Mutex<Co
 
     let path_str = path.to_string_lossy().to_string();
     button.connect_clicked(move |_| {
-        if let Err(_e) = quickshell::set_wallpaper(&path_str) {
-        } else {
-            // Update config
-            let mut cfg = config.lock().unwrap();
-            cfg.last_wallpaper = Some(path_str.clone());
-            drop(cfg);
-            // Note: Save would typically happen here
-        }
+        let path_str = path_str.clone();
+        let config = Arc::clone(&config);
+        gtk4::glib::MainContext::default().spawn_local(async move {
+            // Checking a GIF / WebP for animation reads the file - keep it off the main thread
+            let path_clone = path_str.clone();
+            let result = gio::spawn_blocking(move || quickshell::set_wallpaper(&path_clone).map_err(|e| e.to_string())).await;
+            if let Ok(Ok(())) = result {
+                // Update config
+                config.lock().unwrap().last_wallpaper = Some(path_str);
+            }
+        });
     });
 
     button
 }
-
-fn find_wallpapers(path: &PathBuf) -> Vec<PathBuf> {
-    let mut wallpapers = Vec::new();
-    
-    if !path.exists() {
-        return wallpapers;
-    }
-
-    if let Ok(entries) = fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_file() {
-                if let Some(ext) = path.extension() {
-                    let ext_lower = ext.to_string_lossy().to_lowercase();
-                    if matches!(ext_lower.as_str(), "jpg" | "jpeg" | "png" | "webp" | "gif") {
-                        wallpapers.push(path);
-                    }
-                }
-            }
-        }
-    }
-
-    // Sort
-    wallpapers.sort();
-    // wallpapers.truncate(6); // Removed truncation for 3x5 grid support
-    wallpapers
-}
-
-
-
-
-
@@ -1,7 +1,9 @@
 use libadwaita::prelude::*;
-use gtk4::{Box as GtkBox, Orientation, Switch, Align};
+use gtk4::{gio, Box as GtkBox, Button, Orientation, Switch, Align};
 use std::sync::{Arc, Mutex};
+use crate::core::backgrounds;
 use crate::core::config::ColorConfig;
+use crate::widgets::color_picker;
 
 pub struct LockScreenTab {
     widget: gtk4::ScrolledWindow,
@@ -30,9 +32,12 @@ impl LockScreenTab {
         title.set_margin_bottom(24);
         main_box.append(&title);
 
+        main_box.append(&create_background_group(Arc::clone(&config)));
+
         let input_group = libadwaita::PreferencesGroup::builder()
             .title("Widgets")
             .description("Manage widgets displayed on the lock screen.")
+            .margin_top(24)
             .build();
 
         let current_config = config.lock().unwrap();
@@ -130,6 +135,30 @@ impl LockScreenTab {
         input_group.add(&row_screensaver_widgets);
 
         main_box.append(&input_group);
+
+        let colors_group = libadwaita::PreferencesGroup::builder()
+            .title("Colors")
+            .description("Override the global palette on the lock screen only.")
+            .margin_top(24)
+            .build();
+        for (role, label) in color_picker::OVERRIDE_ROLES {
+            let role_config = Arc::clone(&config);
+            let picker = color_picker::create_override_picker(
+                label,
+                current_config.component_override("lockscreen", role).as_deref(),
+                &current_config.global_color(role),
+                move |color| {
+                    if let Ok(mut c) = role_config.lock() {
+                        c.set_component_color("lockscreen", role, color.as_deref());
+                        let _ = c.save();
+                    }
+                },
+            );
+            let row = libadwaita::ActionRow::builder().title(*label).build();
+            row.add_suffix(&picker);
+            colors_group.add(&row);
+        }
+        main_box.append(&colors_group);
         scrolled.set_child(Some(&main_box));
 
         Self {
@@ -142,6 +171,134 @@ impl LockScreenTab {
     }
 }
 
+/// Palette roles a solid lock screen background can use (config key, label).
+const BACKGROUND_COLOR_ROLES: &[(&str, &str)] = &[
+    ("background", "Background"),
+    ("primary", "Primary"),
+    ("secondary", "Secondary"),
+    ("accent", "Accent"),
+];
+
+fn create_background_group(config: Arc<Mutex<ColorConfig>>) -> libadwaita::PreferencesGroup {
+    let group = libadwaita::PreferencesGroup::builder()
+        .title("Background")
+        .description("Blurred and dimmed copies are rendered whenever the wallpaper changes.")
+        .build();
+
+    let (mode, image, role) = {
+        let c = config.lock().unwrap();
+        (
+            c.lockscreen_background.clone().unwrap_or_else(|| backgrounds::DEFAULT_LOCKSCREEN_MODE.to_string()),
+            c.lockscreen_image.clone().filter(|i| !i.is_empty()),
+            c.lockscreen_color_role.clone().unwrap_or_else(|| "background".to_string()),
+        )
+    };
+
+    // Separate image, only used in "image" mode
+    let image_row = libadwaita::ActionRow::builder()
+        .title("Image")
+        .subtitle(image.as_deref().unwrap_or("Same as the wallpaper until one is chosen"))
+        .visible(mode == "image")
+        .build();
+    let choose_button = Button::builder().label("Choose…").valign(Align::Center).build();
+    {
+        let config = Arc::clone(&config);
+        let image_row = image_row.clone();
+        choose_button.connect_clicked(move |button| {
+            let filter = gtk4::FileFilter::new();
+            filter.set_name(Some("Images"));
+            filter.add_pixbuf_formats();
+            let filters = gio::ListStore::new::<gtk4::FileFilter>();
+            filters.append(&filter);
+
+            let dialog = gtk4::FileDialog::new();
+            dialog.set_title("Lock Screen Image");
+            dialog.set_filters(Some(&filters));
+            let window = button.root().and_downcast::<gtk4::Window>();
+            let config = Arc::clone(&config);
+            let image_row = image_row.clone();
+            dialog.open(window.as_ref(), gio::Cancellable::NONE, move |result| {
+                let Some(path) = result.ok().and_then(|file| file.path()) else {
+                    return;
+                };
+                let path = path.to_string_lossy().to_string();
+                if let Ok(mut c) = config.lock() {
+                    c.set_lockscreen_image(&path);
+                    let _ = c.save();
+                }
+                image_row.set_subtitle(&path);
+            });
+        });
+    }
+    image_row.add_suffix(&choose_button);
+
+    // Palette color, only used in "color" mode
+    let role_config = Arc::clone(&config);
+    let color_row = create_choice_row("Color", BACKGROUND_COLOR_ROLES, &role, move |role| {
+        if let Ok(mut c) = role_config.lock() {
+            c.set_lockscreen_color_role(role);
+            let _ = c.save();
+        }
+    });
+    color_row.set_visible(mode == "color");
+
+    let mode_config = Arc::clone(&config);
+    let mode_row = {
+        let image_row = image_row.clone();
+        let color_row = color_row.clone();
+        create_choice_row("Style", backgrounds::LOCKSCREEN_MODES, &mode, move |mode| {
+            if let Ok(mut c) = mode_config.lock() {
+                c.set_lockscreen_background(mode);
+                let _ = c.save();
+            }
+            image_row.set_visible(mode == "image");
+            color_row.set_visible(mode == "color");
+        })
+    };
+
+    group.add(&mode_row);
+    group.add(&image_row);
+    group.add(&color_row);
+    group
+}
+
+/// Row with one button per option; the selected one is highlighted.
+fn create_choice_row<F>(title: &str, options: &[(&str, &str)], selected: &str, callback: F) -> libadwaita::ActionRow
+where
+    F: Fn(&str) + 'static,
+{
+    let row = libadwaita::ActionRow::builder().title(title).build();
+    let buttons_box = GtkBox::new(Orientation::Horizontal, 6);
+    buttons_box.set_valign(Align::Center);
+
+    let callback = std::rc::Rc::new(callback);
+    let buttons: Vec<(Button, String)> = options
+        .iter()
+        .map(|(value, label)| {
+            let btn = Button::with_label(label);
+            if *value == selected {
+                btn.add_css_class("suggested-action");
+            }
+            buttons_box.append(&btn);
+            (btn, value.to_string())
+        })
+        .collect();
+    for (btn, value) in buttons.clone() {
+        let buttons = buttons.clone();
+        let callback = std::rc::Rc::clone(&callback);
+        btn.connect_clicked(move |_| {
+            for (b, v) in buttons.iter() {
+                if v == &value { b.add_css_class("suggested-action"); }
+                else { b.remove_css_class("suggested-action"); }
+            }
+            callback(&value);
+        });
+    }
+
+    row.add_suffix(&buttons_box);
+    row
+}
+
 fn create_switch_row<F>(title: &str, subtitle: &str, initial: bool, callback: F) -> libadwaita::ActionRow
 where
     F: Fn(bool) + 'static,
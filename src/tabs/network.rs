@@ -1,5 +1,5 @@
 use gtk4::prelude::*;
-use gtk4::{Box as GtkBox, Orientation, Label, ScrolledWindow, Switch};
+use gtk4::{Box as GtkBox, Button, Orientation, Label, PasswordEntry, ScrolledWindow, Switch};
 use gtk4::gio;
 use std::sync::{Arc, Mutex};
 use std::process::Command;
@@ -50,11 +50,23 @@ impl NetworkTab {
         let (wifi_toggle_row, wifi_toggle) = create_wifi_toggle_row();
         wifi_card.append(&wifi_toggle_row);
 
-        let wifi_info_row = create_wifi_info_row(wifi_toggle.clone());
+        let (wifi_info_row, wifi_info_label) = create_wifi_info_row(wifi_toggle.clone());
         wifi_card.append(&wifi_info_row);
 
         content.append(&wifi_card);
 
+        // --- Available Networks Group ---
+        add_group_header(&content, "Available Networks");
+        let networks_card = GtkBox::new(Orientation::Vertical, 0);
+        networks_card.add_css_class("card");
+
+        let networks_container = GtkBox::new(Orientation::Vertical, 0);
+        networks_card.append(&create_rescan_row(&networks_container, &wifi_info_label));
+        networks_card.append(&networks_container);
+        refresh_wifi_networks(&networks_container, &wifi_info_label, false);
+
+        content.append(&networks_card);
+
         // --- Interfaces Group ---
         add_group_header(&content, "Network Interfaces");
         let interfaces_card = GtkBox::new(Orientation::Vertical, 0);
@@ -116,7 +128,7 @@ fn create_wifi_toggle_row() -> (GtkBox, Switch) {
     (create_card_row("Enable Wi-Fi", wifi_toggle.clone()), wifi_toggle)
 }
 
-fn create_wifi_info_row(_toggle: Switch) -> GtkBox {
+fn create_wifi_info_row(_toggle: Switch) -> (GtkBox, Label) {
     let info_label = Label::new(Some("…"));
     info_label.add_css_class("row-description");
     
@@ -126,7 +138,200 @@ fn create_wifi_info_row(_toggle: Switch) -> GtkBox {
         info_label_clone.set_text(&current);
     });
 
-    create_card_row("Connected to", info_label)
+    (create_card_row("Connected to", info_label.clone()), info_label)
+}
+
+fn create_rescan_row(container: &GtkBox, info_label: &Label) -> GtkBox {
+    let btn = Button::with_label("Rescan");
+    btn.add_css_class("flat");
+
+    let container_clone = container.clone();
+    let info_label = info_label.clone();
+    btn.connect_clicked(move |_| {
+        refresh_wifi_networks(&container_clone, &info_label, true);
+    });
+
+    create_card_row("Nearby Networks", btn)
+}
+
+/// Fill `container` with nearby networks; `rescan` asks NetworkManager for a fresh scan first.
+/// Also brings the "Connected to" row up to date.
+fn refresh_wifi_networks(container: &GtkBox, info_label: &Label, rescan: bool) {
+    while let Some(child) = container.first_child() { container.remove(&child); }
+    let loading = Label::new(Some(if rescan { "Scanning…" } else { "Loading…" }));
+    loading.add_css_class("dim-label");
+    loading.set_margin_top(12);
+    loading.set_margin_bottom(12);
+    container.append(&loading);
+
+    let container_clone = container.clone();
+    let info_label = info_label.clone();
+    gtk4::glib::MainContext::default().spawn_local(async move {
+        let (networks, current) = gio::spawn_blocking(move || (get_wifi_networks(rescan), get_current_wifi()))
+            .await
+            .unwrap_or_else(|_| (Vec::new(), "Not connected".to_string()));
+        info_label.set_text(&current);
+
+        while let Some(child) = container_clone.first_child() { container_clone.remove(&child); }
+        if networks.is_empty() {
+            let p = Label::new(Some("No networks found"));
+            p.add_css_class("dim-label");
+            p.set_margin_top(12);
+            p.set_margin_bottom(12);
+            container_clone.append(&p);
+        } else {
+            for network in networks {
+                container_clone.append(&create_network_row(&network, &container_clone, &info_label));
+            }
+        }
+    });
+}
+
+fn create_network_row(network: &WifiNetwork, container: &GtkBox, info_label: &Label) -> GtkBox {
+    let row = GtkBox::new(Orientation::Horizontal, 12);
+    row.add_css_class("card-row");
+
+    let icon = Label::new(Some(signal_icon(network.signal, network.is_secured())));
+    icon.add_css_class("device-status-icon");
+    icon.set_margin_end(8);
+    icon.set_valign(gtk4::Align::Start);
+    icon.set_tooltip_text(Some(&format!("{}%", network.signal)));
+    row.append(&icon);
+
+    let text_box = GtkBox::new(Orientation::Vertical, 2);
+    text_box.set_hexpand(true);
+
+    let name = Label::new(Some(&network.ssid));
+    name.add_css_class("row-title");
+    name.set_halign(gtk4::Align::Start);
+    name.set_ellipsize(gtk4::pango::EllipsizeMode::End);
+    text_box.append(&name);
+
+    let state = if network.active { "Connected" } else if network.known { "Saved" } else { "Available" };
+    let security = if network.is_secured() { network.security.as_str() } else { "Open" };
+    let desc = Label::new(Some(&format!("{} • {} • {}%", state, security, network.signal)));
+    desc.add_css_class("row-description");
+    desc.set_halign(gtk4::Align::Start);
+    text_box.append(&desc);
+
+    // Password prompt, shown when joining a secured network without saved credentials
+    let password_box = GtkBox::new(Orientation::Horizontal, 8);
+    password_box.set_margin_top(6);
+    password_box.set_visible(false);
+    let password_entry = PasswordEntry::new();
+    password_entry.set_show_peek_icon(true);
+    password_entry.set_hexpand(true);
+    password_entry.set_placeholder_text(Some("Password"));
+    password_box.append(&password_entry);
+    let join_btn = Button::with_label("Join");
+    join_btn.add_css_class("suggested-action");
+    password_box.append(&join_btn);
+    text_box.append(&password_box);
+
+    let error_label = Label::new(None);
+    error_label.add_css_class("error");
+    error_label.add_css_class("row-description");
+    error_label.set_halign(gtk4::Align::Start);
+    error_label.set_wrap(true);
+    error_label.set_xalign(0.0);
+    error_label.set_visible(false);
+    text_box.append(&error_label);
+
+    row.append(&text_box);
+
+    let connect_btn = Button::with_label(if network.active { "Disconnect" } else { "Connect" });
+    connect_btn.add_css_class("flat");
+    connect_btn.set_valign(gtk4::Align::Start);
+    row.append(&connect_btn);
+
+    // Runs `action` off the main thread; on success the list is reloaded, otherwise the error
+    // is shown in the row and `on_error` gets nmcli's message.
+    let run = {
+        let container = container.clone();
+        let info_label = info_label.clone();
+        let connect_btn = connect_btn.clone();
+        let join_btn = join_btn.clone();
+        let error_label = error_label.clone();
+        std::rc::Rc::new(move |action: Box<dyn FnOnce() -> Result<(), String> + Send>, on_error: Box<dyn Fn(&str)>| {
+            connect_btn.set_sensitive(false);
+            join_btn.set_sensitive(false);
+            error_label.set_visible(false);
+            let container = container.clone();
+            let info_label = info_label.clone();
+            let connect_btn = connect_btn.clone();
+            let join_btn = join_btn.clone();
+            let error_label = error_label.clone();
+            gtk4::glib::MainContext::default().spawn_local(async move {
+                let result = gio::spawn_blocking(action).await.unwrap_or_else(|_| Err("Connection attempt failed".to_string()));
+                match result {
+                    Ok(()) => refresh_wifi_networks(&container, &info_label, false),
+                    Err(e) => {
+                        connect_btn.set_sensitive(true);
+                        join_btn.set_sensitive(true);
+                        error_label.set_text(&e);
+                        error_label.set_visible(true);
+                        on_error(&e);
+                    }
+                }
+            });
+        })
+    };
+
+    {
+        let run = run.clone();
+        let ssid = network.ssid.clone();
+        let password_box = password_box.clone();
+        let password_entry = password_entry.clone();
+        let active = network.active;
+        let needs_password = network.is_secured() && !network.known;
+        connect_btn.connect_clicked(move |_| {
+            if active {
+                run(Box::new(disconnect_wifi), Box::new(|_| {}));
+            } else if needs_password {
+                password_box.set_visible(true);
+                password_entry.grab_focus();
+            } else {
+                let ssid = ssid.clone();
+                let password_box = password_box.clone();
+                // Saved credentials may be outdated - ask for the password again
+                run(
+                    Box::new(move || connect_wifi(&ssid, None)),
+                    Box::new(move |e| password_box.set_visible(needs_secrets(e))),
+                );
+            }
+        });
+    }
+
+    let join = {
+        let ssid = network.ssid.clone();
+        let password_entry = password_entry.clone();
+        move || {
+            let ssid = ssid.clone();
+            let password = password_entry.text().to_string();
+            run(Box::new(move || connect_wifi(&ssid, Some(&password))), Box::new(|_| {}));
+        }
+    };
+    {
+        let join = join.clone();
+        join_btn.connect_clicked(move |_| join());
+    }
+    password_entry.connect_activate(move |_| join());
+
+    row
+}
+
+/// Nerd Font Wi-Fi glyph with one to four bars; locked variants for secured networks.
+fn signal_icon(signal: u8, secured: bool) -> &'static str {
+    match (signal, secured) {
+        (0..=24, false) => "󰤟",
+        (25..=49, false) => "󰤢",
+        (50..=74, false) => "󰤥",
+        (_, false) => "󰤨",
+        (0..=24, true) => "󰤡",
+        (25..=49, true) => "󰤤",
+        (50..=74, true) => "󰤧",
+        (_, true) => "󰤪",
+    }
 }
 
 
@@ -191,6 +396,24 @@ fn create_interface_row(interface: &NetworkInterface) -> GtkBox {
     row
 }
 
+#[derive(Debug, Clone)]
+struct WifiNetwork {
+    ssid: String,
+    /// Signal strength in percent
+    signal: u8,
+    /// As reported by nmcli, e.g. "WPA2" or "WPA1 WPA2"; empty for open networks
+    security: String,
+    active: bool,
+    /// A saved connection exists for this SSID
+    known: bool,
+}
+
+impl WifiNetwork {
+    fn is_secured(&self) -> bool {
+        !self.security.is_empty() && self.security != "--"
+    }
+}
+
 #[derive(Debug, Clone)]
 struct NetworkInterface {
     name: String,
@@ -243,3 +466,139 @@ fn get_current_wifi() -> String {
         .and_then(|s| s.lines().find(|l| l.starts_with("yes:")).map(|l| l.split(':').nth(1).unwrap_or("Unknown").to_string()))
         .unwrap_or_else(|| "Not connected".to_string())
 }
+
+/// Split a line of `nmcli -t` output: fields are separated by ':' and literal colons
+/// and backslashes are escaped with a backslash.
+fn split_terse(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    fields.last_mut().unwrap().push(escaped);
+                }
+            }
+            ':' => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// SSIDs of the saved Wi-Fi connections. Read from each profile, since its name
+/// doesn't have to match the network.
+fn saved_wifi_connections() -> HashSet<String> {
+    let uuids: Vec<String> = Command::new("nmcli").args(["-t", "-f", "UUID,TYPE", "connection", "show"]).output().ok()
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .map(|s| {
+            s.lines()
+                .map(split_terse)
+                .filter(|f| f.len() >= 2 && f[1] == "802-11-wireless")
+                .map(|f| f[0].clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    uuids
+        .iter()
+        .filter_map(|uuid| {
+            Command::new("nmcli").args(["-g", "802-11-wireless.ssid", "connection", "show", "uuid", uuid]).output().ok()
+                .and_then(|o| String::from_utf8(o.stdout).ok())
+                .map(|s| s.trim_end_matches('\n').to_string())
+                .filter(|ssid| !ssid.is_empty())
+        })
+        .collect()
+}
+
+/// Nearby networks, one entry per SSID: the connected one first, then by signal strength.
+/// Hidden networks (no SSID) are left out.
+fn get_wifi_networks(rescan: bool) -> Vec<WifiNetwork> {
+    let known = saved_wifi_connections();
+    let output = Command::new("nmcli")
+        .args(["-t", "-f", "IN-USE,SSID,SIGNAL,SECURITY", "dev", "wifi", "list", "--rescan", if rescan { "yes" } else { "auto" }])
+        .output();
+    let Some(output_str) = output.ok().and_then(|o| String::from_utf8(o.stdout).ok()) else {
+        return Vec::new();
+    };
+
+    let mut networks: Vec<WifiNetwork> = Vec::new();
+    for line in output_str.lines() {
+        let fields = split_terse(line);
+        if fields.len() < 4 || fields[1].is_empty() {
+            continue;
+        }
+        let network = WifiNetwork {
+            ssid: fields[1].clone(),
+            signal: fields[2].parse().unwrap_or(0),
+            security: fields[3].clone(),
+            active: fields[0] == "*",
+            known: known.contains(&fields[1]),
+        };
+        // Several access points can share an SSID - keep the connected or strongest one
+        match networks.iter_mut().find(|n| n.ssid == network.ssid) {
+            Some(existing) => {
+                if network.active || (!existing.active && network.signal > existing.signal) {
+                    *existing = network;
+                }
+            }
+            None => networks.push(network),
+        }
+    }
+    networks.sort_by(|a, b| b.active.cmp(&a.active).then(b.signal.cmp(&a.signal)));
+    networks
+}
+
+/// nmcli's error message (without the "Error: " prefix) for a failed command.
+fn nmcli_result(output: std::io::Result<std::process::Output>) -> Result<(), String> {
+    let output = output.map_err(|e| format!("Could not run nmcli: {}", e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let message = stderr.trim().trim_start_matches("Error: ");
+    Err(if message.is_empty() { "Connection failed".to_string() } else { message.to_string() })
+}
+
+/// Join `ssid`; without a password NetworkManager uses the saved connection, if any.
+/// The password goes to nmcli's prompt on stdin, never on its command line where other
+/// users could read it.
+fn connect_wifi(ssid: &str, password: Option<&str>) -> Result<(), String> {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let Some(password) = password else {
+        return nmcli_result(Command::new("nmcli").args(["dev", "wifi", "connect", ssid]).stdin(Stdio::null()).output());
+    };
+    let mut child = Command::new("nmcli")
+        .args(["--ask", "dev", "wifi", "connect", ssid])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Could not run nmcli: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = writeln!(stdin, "{}", password);
+    }
+    nmcli_result(child.wait_with_output())
+}
+
+/// Take down the active Wi-Fi connection.
+fn disconnect_wifi() -> Result<(), String> {
+    let active = Command::new("nmcli").args(["-t", "-f", "NAME,TYPE", "connection", "show", "--active"]).output().ok()
+        .and_then(|o| String::from_utf8(o.stdout).ok())
+        .and_then(|s| {
+            s.lines()
+                .map(split_terse)
+                .find(|f| f.len() >= 2 && f[1] == "802-11-wireless")
+                .map(|f| f[0].clone())
+        })
+        .ok_or_else(|| "Not connected".to_string())?;
+    nmcli_result(Command::new("nmcli").args(["connection", "down", "id", &active]).output())
+}
+
+/// Whether a failed connect was due to missing or wrong credentials.
+fn needs_secrets(error: &str) -> bool {
+    let error = error.to_lowercase();
+    error.contains("secrets") || error.contains("password")
+}
@@ -4,6 +4,8 @@ use std::sync::{Arc, Mutex};
 
 use crate::core::config::ColorConfig;
 use crate::core::quickshell;
+use crate::widgets::color_picker;
+use crate::widgets::layout_preview;
 
 fn schedule_notify_color_change_ms(ms: u32) {
     gtk4::glib::timeout_add_local(std::time::Duration::from_millis(ms as u64), move || {
@@ -40,6 +42,9 @@ impl NotificationsTab {
         title.set_margin_bottom(24);
         content.append(&title);
 
+        // Schematic preview, redrawn as soon as a layout control changes
+        content.append(&layout_preview::create_layout_preview(&config.lock().unwrap()));
+
         let add_group_header = |box_: &GtkBox, label: &str| {
             let l = Label::new(Some(label));
             l.add_css_class("group-header");
@@ -79,6 +84,10 @@ impl NotificationsTab {
 
         content.append(&layout_card);
 
+        // --- Colors Group (overrides on top of the global palette) ---
+        add_group_header(&content, "Colors");
+        content.append(&color_picker::create_color_overrides_card("notifications", Arc::clone(&config)));
+
         scrolled.set_child(Some(&content));
 
         Self {
@@ -159,6 +168,7 @@ fn create_position_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
         let value_clone = value.clone();
         let buttons_clone = buttons.clone();
         btn.connect_clicked(move |_| {
+            layout_preview::preview_update(|c| c.set_notification_position(&value_clone));
             let mut cfg = ColorConfig::load();
             cfg.set_notification_position(&value_clone);
             if cfg.save().is_ok() {
@@ -1,8 +1,8 @@
 use gtk4::prelude::*;
-use gtk4::{Box as GtkBox, Orientation, Label, ScrolledWindow, Button};
+use gtk4::{Box as GtkBox, Orientation, Label, ScrolledWindow, Scale};
 use std::sync::{Arc, Mutex};
 
-use crate::core::config::ColorConfig;
+use crate::core::config::{clamp_scale, ColorConfig, MAX_SCALE, MIN_SCALE, SCALE_STEP};
 use crate::core::quickshell;
 
 fn schedule_notify_color_change_ms(ms: u32) {
@@ -47,6 +47,10 @@ impl QuickshellTab {
         let scaling_row = create_scaling_row(Arc::clone(&config));
         system_card.append(&scaling_row);
 
+        // Text Scale Row - applied live, no restart needed
+        let text_scaling_row = create_text_scaling_row(Arc::clone(&config));
+        system_card.append(&text_scaling_row);
+
         content.append(&system_card);
 
         scrolled.set_child(Some(&content));
@@ -78,80 +82,60 @@ fn create_card_row(label: &str, widget: impl IsA<gtk4::Widget>) -> GtkBox {
 }
 
 fn create_scaling_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
-    let box_ = GtkBox::new(Orientation::Horizontal, 8);
-    
     let current_scale = config.lock().unwrap().ui_scale.unwrap_or(100);
-    
-    let btn_75 = Button::with_label("75%");
-    let btn_100 = Button::with_label("100%");
-    let btn_125 = Button::with_label("125%");
-    
-    let update_btn_styles = {
-        let btn_75 = btn_75.clone();
-        let btn_100 = btn_100.clone();
-        let btn_125 = btn_125.clone();
-        
-        move |scale: u32| {
-            btn_75.remove_css_class("suggested-action");
-            btn_100.remove_css_class("suggested-action");
-            btn_125.remove_css_class("suggested-action");
-            
-            match scale {
-                75 => btn_75.add_css_class("suggested-action"),
-                100 => btn_100.add_css_class("suggested-action"),
-                125 => btn_125.add_css_class("suggested-action"),
-                _ => {}
-            }
-        }
-    };
-    
-    update_btn_styles(current_scale as u32);
+    create_scale_slider_row("UI Scale", current_scale, config, |cfg, value| cfg.set_ui_scale(value))
+}
 
-    {
-        let config = config.clone();
-        let update_btn_styles = update_btn_styles.clone();
-        btn_75.connect_clicked(move |_| {
-            let mut cfg = ColorConfig::load();
-            cfg.set_ui_scale(75);
-            if cfg.save().is_ok() {
-                *config.lock().unwrap() = cfg.clone();
-                update_btn_styles(75);
-                schedule_notify_color_change_ms(200);
-            }
-        });
-    }
+fn create_text_scaling_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let current_scale = config.lock().unwrap().text_scale.unwrap_or(100);
+    create_scale_slider_row("Text Scale", current_scale, config, |cfg, value| cfg.set_text_scale(value))
+}
 
-    {
-        let config = config.clone();
-        let update_btn_styles = update_btn_styles.clone();
-        btn_100.connect_clicked(move |_| {
-            let mut cfg = ColorConfig::load();
-            cfg.set_ui_scale(100);
-            if cfg.save().is_ok() {
-                *config.lock().unwrap() = cfg.clone();
-                update_btn_styles(100);
-                schedule_notify_color_change_ms(200);
-            }
-        });
+/// Percentage slider (MIN_SCALE..=MAX_SCALE in SCALE_STEP steps) that saves once dragging settles.
+fn create_scale_slider_row<F>(label: &str, current: u8, config: Arc<Mutex<ColorConfig>>, apply: F) -> GtkBox
+where
+    F: Fn(&mut ColorConfig, u8) + 'static,
+{
+    let scale = Scale::with_range(Orientation::Horizontal, MIN_SCALE as f64, MAX_SCALE as f64, SCALE_STEP as f64);
+    scale.set_value(clamp_scale(current) as f64);
+    scale.set_digits(0);
+    scale.set_draw_value(true);
+    scale.set_value_pos(gtk4::PositionType::Right);
+    scale.set_format_value_func(|_, value| format!("{}%", value.round() as u32));
+    scale.set_hexpand(true);
+    scale.set_size_request(240, -1);
+    for mark in [75.0, 100.0, 125.0, 150.0] {
+        scale.add_mark(mark, gtk4::PositionType::Bottom, None);
     }
 
-    {
+    let apply = std::rc::Rc::new(apply);
+    let debounce_id = Arc::new(Mutex::new(Option::<gtk4::glib::SourceId>::None));
+    scale.connect_value_changed(move |s| {
+        // Snap drags to whole steps
+        let value = clamp_scale(s.value().round() as u8);
+        if (s.value() - value as f64).abs() > f64::EPSILON {
+            s.set_value(value as f64);
+            return;
+        }
+
+        let mut db_id = debounce_id.lock().unwrap();
+        if let Some(id) = db_id.take() {
+            id.remove();
+        }
         let config = config.clone();
-        let update_btn_styles = update_btn_styles.clone();
-        btn_125.connect_clicked(move |_| {
+        let apply = apply.clone();
+        let debounce_id_inner = debounce_id.clone();
+        *db_id = Some(gtk4::glib::timeout_add_local(std::time::Duration::from_millis(400), move || {
+            debounce_id_inner.lock().unwrap().take();
             let mut cfg = ColorConfig::load();
-            cfg.set_ui_scale(125);
+            apply(&mut cfg, value);
             if cfg.save().is_ok() {
                 *config.lock().unwrap() = cfg.clone();
-                update_btn_styles(125);
                 schedule_notify_color_change_ms(200);
             }
-        });
-    }
-
-    box_.append(&btn_75);
-    box_.append(&btn_100);
-    box_.append(&btn_125);
+            gtk4::glib::ControlFlow::Break
+        }));
+    });
 
-    create_card_row("UI Scale", box_)
+    create_card_row(label, scale)
 }
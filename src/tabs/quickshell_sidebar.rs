@@ -4,6 +4,8 @@ use std::sync::{Arc, Mutex};
 
 use crate::core::config::ColorConfig;
 use crate::core::quickshell;
+use crate::widgets::color_picker;
+use crate::widgets::layout_preview;
 
 fn schedule_notify_color_change_ms(ms: u32) {
     gtk4::glib::timeout_add_local(std::time::Duration::from_millis(ms as u64), move || {
@@ -40,6 +42,9 @@ impl QuickshellSidebarTab {
         title.set_margin_bottom(24);
         content.append(&title);
 
+        // Schematic preview, redrawn as soon as a layout control changes
+        content.append(&layout_preview::create_layout_preview(&config.lock().unwrap()));
+
         let sidebar_card = GtkBox::new(Orientation::Vertical, 0);
         sidebar_card.add_css_class("card");
 
@@ -55,6 +60,14 @@ impl QuickshellSidebarTab {
         
         content.append(&sidebar_card);
 
+        // --- Colors Group (overrides on top of the global palette) ---
+        let colors_header = Label::new(Some("Colors"));
+        colors_header.add_css_class("group-header");
+        colors_header.set_halign(gtk4::Align::Start);
+        colors_header.set_margin_top(24);
+        content.append(&colors_header);
+        content.append(&color_picker::create_color_overrides_card("sidebar", Arc::clone(&config)));
+
         scrolled.set_child(Some(&content));
 
         Self {
@@ -92,6 +105,7 @@ fn create_sidebar_visible_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
     {
         let config = config.clone();
         switch.connect_active_notify(move |s| {
+            layout_preview::preview_update(|c| c.set_sidebar_visible(s.is_active()));
             let mut cfg = ColorConfig::load();
             cfg.set_sidebar_visible(s.is_active());
             if cfg.save().is_ok() {
@@ -168,6 +182,7 @@ fn create_sidebar_position_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
 
     let bind_click = |btn: &Button, val: &'static str, config: Arc<Mutex<ColorConfig>>, updater: Box<dyn Fn(&str)>| {
         btn.connect_clicked(move |_| {
+            layout_preview::preview_update(|c| c.set_sidebar_position(val.to_lowercase().as_str()));
             let mut cfg = ColorConfig::load();
             cfg.set_sidebar_position(val.to_lowercase().as_str());
             if cfg.save().is_ok() {
@@ -223,6 +238,7 @@ fn create_sidebar_workspace_mode_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox
         let cfg_ref = config.clone();
         let up = update.clone();
         btn.connect_clicked(move |_| {
+            layout_preview::preview_update(|c| c.set_sidebar_workspace_mode(mode));
             let mut cfg = ColorConfig::load();
             cfg.set_sidebar_workspace_mode(mode);
             if cfg.save().is_ok() {
@@ -274,6 +290,7 @@ fn create_sidebar_style_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
         let cfg_ref = config.clone();
         let up = update.clone();
         btn_dots.connect_clicked(move |_| {
+            layout_preview::preview_update(|c| c.set_sidebar_style("dots"));
             let mut cfg = ColorConfig::load();
             cfg.set_sidebar_style("dots");
             if cfg.save().is_ok() {
@@ -288,6 +305,7 @@ fn create_sidebar_style_row(config: Arc<Mutex<ColorConfig>>) -> GtkBox {
         let cfg_ref = config.clone();
         let up = update.clone();
         btn_lines.connect_clicked(move |_| {
+            layout_preview::preview_update(|c| c.set_sidebar_style("lines"));
             let mut cfg = ColorConfig::load();
             cfg.set_sidebar_style("lines");
             if cfg.save().is_ok() {
@@ -1,2 +1,173 @@
-// Placeholder for color picker widget
-// Can be extended later if needed
+use gtk4::prelude::*;
+use gtk4::{gdk, Box as GtkBox, Button, ColorDialog, ColorDialogButton, Label, Orientation};
+use std::sync::{Arc, Mutex};
+
+use crate::core::color;
+use crate::core::config::ColorConfig;
+use crate::core::quickshell;
+use crate::widgets::layout_preview;
+
+/// Card row with a title, optional description and a color button on the right.
+/// `on_change` receives the picked color as "#rrggbb".
+pub fn create_color_picker_row<F>(title: &str, description: Option<&str>, initial: &str, on_change: F) -> GtkBox
+where
+    F: Fn(String) + 'static,
+{
+    let row = GtkBox::new(Orientation::Horizontal, 12);
+    row.add_css_class("card-row");
+    row.set_valign(gtk4::Align::Center);
+
+    let text_box = GtkBox::new(Orientation::Vertical, 2);
+    text_box.set_hexpand(true);
+
+    let title_label = Label::new(Some(title));
+    title_label.add_css_class("row-title");
+    title_label.set_halign(gtk4::Align::Start);
+    text_box.append(&title_label);
+
+    if let Some(description) = description {
+        let desc_label = Label::new(Some(description));
+        desc_label.add_css_class("row-description");
+        desc_label.set_halign(gtk4::Align::Start);
+        text_box.append(&desc_label);
+    }
+
+    row.append(&text_box);
+
+    let dialog = ColorDialog::new();
+    dialog.set_with_alpha(false);
+    dialog.set_title(title);
+
+    let button = ColorDialogButton::new(Some(dialog));
+    button.set_valign(gtk4::Align::Center);
+    if let Ok(rgba) = gdk::RGBA::parse(color::to_hex(initial)) {
+        button.set_rgba(&rgba);
+    }
+
+    // Connected after the initial set_rgba so building the row doesn't trigger a save
+    button.connect_rgba_notify(move |b| {
+        on_change(rgba_to_hex(&b.rgba()));
+    });
+
+    row.append(&button);
+    row
+}
+
+/// Color roles that components can override (config key, label).
+pub const OVERRIDE_ROLES: &[(&str, &str)] = &[
+    ("background", "Background"),
+    ("primary", "Primary"),
+    ("secondary", "Secondary"),
+    ("text", "Text"),
+    ("accent", "Accent"),
+];
+
+/// Card with one override picker per role for a Quickshell `component` ("sidebar", "dashboard", ...).
+/// Changes are previewed, saved and sent to Quickshell.
+pub fn create_color_overrides_card(component: &'static str, config: Arc<Mutex<ColorConfig>>) -> GtkBox {
+    let card = GtkBox::new(Orientation::Vertical, 0);
+    card.add_css_class("card");
+    let current = config.lock().unwrap().clone();
+
+    for (role, label) in OVERRIDE_ROLES {
+        let config = Arc::clone(&config);
+        let picker = create_override_picker(
+            label,
+            current.component_override(component, role).as_deref(),
+            &current.global_color(role),
+            move |color| {
+                layout_preview::preview_update(|c| c.set_component_color(component, role, color.as_deref()));
+                let mut cfg = ColorConfig::load();
+                cfg.set_component_color(component, role, color.as_deref());
+                if cfg.save().is_ok() {
+                    *config.lock().unwrap() = cfg.clone();
+                    gtk4::glib::timeout_add_local_once(std::time::Duration::from_millis(200), || {
+                        let _ = quickshell::notify_color_change();
+                    });
+                }
+            },
+        );
+
+        let row = GtkBox::new(Orientation::Horizontal, 12);
+        row.add_css_class("card-row");
+        row.set_valign(gtk4::Align::Center);
+        let title = Label::new(Some(label));
+        title.add_css_class("row-title");
+        title.set_hexpand(true);
+        title.set_halign(gtk4::Align::Start);
+        row.append(&title);
+        row.append(&picker);
+        card.append(&row);
+    }
+
+    card
+}
+
+pub fn rgba_to_hex(rgba: &gdk::RGBA) -> String {
+    let to_u8 = |v: f32| (v * 255.0).round().clamp(0.0, 255.0) as u8;
+    format!("#{:02x}{:02x}{:02x}", to_u8(rgba.red()), to_u8(rgba.green()), to_u8(rgba.blue()))
+}
+
+/// Color button plus a "use global" reset button, for per-component overrides.
+/// `on_change` gets Some("#rrggbb") for a custom color and None when reset to the global one.
+pub fn create_override_picker<F>(title: &str, current: Option<&str>, global: &str, on_change: F) -> GtkBox
+where
+    F: Fn(Option<String>) + 'static,
+{
+    let box_ = GtkBox::new(Orientation::Horizontal, 6);
+    box_.set_valign(gtk4::Align::Center);
+
+    let state = Label::new(Some(if current.is_some() { "Custom" } else { "Global" }));
+    state.add_css_class("row-description");
+    box_.append(&state);
+
+    let dialog = ColorDialog::new();
+    dialog.set_with_alpha(false);
+    dialog.set_title(title);
+    let button = ColorDialogButton::new(Some(dialog));
+    if let Ok(rgba) = gdk::RGBA::parse(color::to_hex(current.unwrap_or(global))) {
+        button.set_rgba(&rgba);
+    }
+    box_.append(&button);
+
+    let reset = Button::from_icon_name("edit-undo-symbolic");
+    reset.set_tooltip_text(Some("Use global color"));
+    reset.add_css_class("flat");
+    reset.set_sensitive(current.is_some());
+    box_.append(&reset);
+
+    let on_change = std::rc::Rc::new(on_change);
+    // Resetting moves the button back to the global color; that notify must not save an override
+    let resetting = std::rc::Rc::new(std::cell::Cell::new(false));
+
+    {
+        let on_change = on_change.clone();
+        let resetting = resetting.clone();
+        let state = state.clone();
+        let reset = reset.clone();
+        button.connect_rgba_notify(move |b| {
+            if resetting.get() {
+                return;
+            }
+            state.set_text("Custom");
+            reset.set_sensitive(true);
+            on_change(Some(rgba_to_hex(&b.rgba())));
+        });
+    }
+
+    {
+        let global = global.to_string();
+        reset.connect_clicked(move |r| {
+            resetting.set(true);
+            if let Ok(rgba) = gdk::RGBA::parse(color::to_hex(&global)) {
+                button.set_rgba(&rgba);
+            }
+            resetting.set(false);
+            state.set_text("Global");
+            r.set_sensitive(false);
+            on_change(None);
+        });
+    }
+
+    box_
+}
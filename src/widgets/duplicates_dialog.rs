@@ -0,0 +1,190 @@
+use gtk4::prelude::*;
+use gtk4::{gio, glib, Box as GtkBox, Button, CheckButton, Label, Orientation, Picture, ScrolledWindow, Spinner};
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use crate::core::duplicates::{self, DuplicateCopy};
+use crate::widgets::wallpaper_tile;
+
+/// Every copy of one picture, with its "Keep" radio button
+type CopyGroup = Vec<(PathBuf, CheckButton)>;
+
+/// Window that hashes `wallpapers`, lists groups of duplicates and trashes all but the chosen copy.
+/// `on_trashed` runs after files were moved to the trash.
+pub fn show_duplicates_dialog<F>(parent: Option<&gtk4::Window>, wallpapers: Vec<PathBuf>, on_trashed: F)
+where
+    F: Fn() + 'static,
+{
+    let window = gtk4::Window::builder()
+        .title("Duplicate Wallpapers")
+        .modal(true)
+        .default_width(640)
+        .default_height(560)
+        .build();
+    window.set_transient_for(parent);
+
+    let root = GtkBox::new(Orientation::Vertical, 0);
+
+    let scrolled = ScrolledWindow::new();
+    scrolled.set_vexpand(true);
+    let content = GtkBox::new(Orientation::Vertical, 12);
+    content.set_margin_start(16);
+    content.set_margin_end(16);
+    content.set_margin_top(16);
+    content.set_margin_bottom(16);
+    scrolled.set_child(Some(&content));
+    root.append(&scrolled);
+
+    let progress = GtkBox::new(Orientation::Vertical, 12);
+    progress.set_valign(gtk4::Align::Center);
+    progress.set_vexpand(true);
+    let spinner = Spinner::new();
+    spinner.set_spinning(true);
+    spinner.set_size_request(32, 32);
+    progress.append(&spinner);
+    let progress_label = Label::new(Some(&format!("Comparing {} wallpapers…", wallpapers.len())));
+    progress_label.add_css_class("row-description");
+    progress.append(&progress_label);
+    content.append(&progress);
+
+    let actions = GtkBox::new(Orientation::Horizontal, 12);
+    actions.set_margin_start(16);
+    actions.set_margin_end(16);
+    actions.set_margin_top(12);
+    actions.set_margin_bottom(12);
+    let summary = Label::new(None);
+    summary.add_css_class("row-description");
+    summary.set_hexpand(true);
+    summary.set_halign(gtk4::Align::Start);
+    actions.append(&summary);
+    let trash_button = Button::with_label("Move Others to Trash");
+    trash_button.add_css_class("destructive-action");
+    trash_button.set_sensitive(false);
+    actions.append(&trash_button);
+    root.append(&actions);
+
+    window.set_child(Some(&root));
+    window.present();
+
+    let groups: Rc<RefCell<Vec<CopyGroup>>> = Rc::new(RefCell::new(Vec::new()));
+
+    {
+        let groups = Rc::clone(&groups);
+        let window = window.clone();
+        trash_button.connect_clicked(move |button| {
+            let mut failed = Vec::new();
+            for group in groups.borrow_mut().iter_mut() {
+                // Trashed copies leave the group, so another click only retries the failures
+                group.retain(|(path, keep)| {
+                    if keep.is_active() {
+                        return true;
+                    }
+                    match duplicates::trash(path) {
+                        Ok(()) => {
+                            keep.set_sensitive(false);
+                            false
+                        }
+                        Err(e) => {
+                            failed.push(format!("{}: {}", path.display(), e.message()));
+                            true
+                        }
+                    }
+                });
+            }
+            on_trashed();
+            if failed.is_empty() {
+                window.close();
+            } else {
+                summary.set_text(&format!("Could not trash {} file(s): {}", failed.len(), failed.join("; ")));
+                button.set_label("Retry");
+            }
+        });
+    }
+
+    let trash_button_c = trash_button.clone();
+    glib::MainContext::default().spawn_local(async move {
+        let found = gio::spawn_blocking(move || duplicates::find_duplicates(&wallpapers)).await.unwrap_or_default();
+        content.remove(&progress);
+
+        if found.is_empty() {
+            let empty = Label::new(Some("No duplicates found"));
+            empty.add_css_class("dim-label");
+            empty.set_vexpand(true);
+            content.append(&empty);
+            return;
+        }
+
+        let extra: usize = found.iter().map(|g| g.len() - 1).sum();
+        let summary_text = format!("{} groups, {} extra copies. The largest copy is kept unless you pick another.", found.len(), extra);
+        let header = Label::new(Some(&summary_text));
+        header.add_css_class("row-description");
+        header.set_wrap(true);
+        header.set_xalign(0.0);
+        content.append(&header);
+
+        for group in found {
+            let card = GtkBox::new(Orientation::Vertical, 0);
+            card.add_css_class("card");
+            let mut rows = Vec::new();
+            let mut first_keep: Option<CheckButton> = None;
+            for copy in group {
+                let keep = CheckButton::with_label("Keep");
+                match first_keep {
+                    Some(ref first) => keep.set_group(Some(first)),
+                    None => {
+                        keep.set_active(true);
+                        first_keep = Some(keep.clone());
+                    }
+                }
+                card.append(&create_copy_row(&copy, &keep));
+                rows.push((copy.path, keep));
+            }
+            content.append(&card);
+            groups.borrow_mut().push(rows);
+        }
+        trash_button_c.set_sensitive(true);
+    });
+}
+
+fn create_copy_row(copy: &DuplicateCopy, keep: &CheckButton) -> GtkBox {
+    let row = GtkBox::new(Orientation::Horizontal, 12);
+    row.add_css_class("card-row");
+
+    let picture = Picture::new();
+    picture.set_content_fit(gtk4::ContentFit::Cover);
+    picture.set_size_request(96, 54);
+    picture.set_can_shrink(true);
+    let placeholder = GtkBox::new(Orientation::Vertical, 0);
+    placeholder.add_css_class("wallpaper-placeholder");
+    let thumb = gtk4::Overlay::new();
+    thumb.set_child(Some(&picture));
+    thumb.add_overlay(&placeholder);
+    wallpaper_tile::load_thumbnail(&picture, &placeholder, &copy.path);
+    row.append(&thumb);
+
+    let text = GtkBox::new(Orientation::Vertical, 2);
+    text.set_hexpand(true);
+    text.set_valign(gtk4::Align::Center);
+    let name = Label::new(Some(&copy.path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()));
+    name.add_css_class("row-title");
+    name.set_halign(gtk4::Align::Start);
+    name.set_ellipsize(gtk4::pango::EllipsizeMode::Middle);
+    text.append(&name);
+    let details = Label::new(Some(&format!(
+        "{}×{}  •  {:.1} MB  •  {}",
+        copy.width,
+        copy.height,
+        copy.size as f64 / 1_048_576.0,
+        copy.path.parent().map(|p| p.display().to_string()).unwrap_or_default()
+    )));
+    details.add_css_class("row-description");
+    details.set_halign(gtk4::Align::Start);
+    details.set_ellipsize(gtk4::pango::EllipsizeMode::Middle);
+    text.append(&details);
+    row.append(&text);
+
+    keep.set_valign(gtk4::Align::Center);
+    row.append(keep);
+    row
+}
@@ -0,0 +1,103 @@
+use gtk4::prelude::*;
+use gtk4::{Box as GtkBox, DropDown, Label, Orientation, SpinButton, StringList, StringObject};
+
+const DEFAULT_LABEL: &str = "System Default";
+
+/// Which installed families a picker offers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontKind {
+    Ui,
+    Mono,
+    /// Nerd Fonts (patched glyph fonts) used for icons in Quickshell
+    Icon,
+}
+
+impl FontKind {
+    pub fn key(&self) -> &'static str {
+        match self {
+            FontKind::Ui => "ui",
+            FontKind::Mono => "mono",
+            FontKind::Icon => "icon",
+        }
+    }
+}
+
+/// Installed font families from Pango (fontconfig on Linux), sorted, filtered for `kind`.
+pub fn installed_families(kind: FontKind) -> Vec<String> {
+    let context = Label::new(None).pango_context();
+    let mut families: Vec<String> = context
+        .list_families()
+        .iter()
+        .filter(|family| match kind {
+            FontKind::Ui => true,
+            FontKind::Mono => family.is_monospace(),
+            FontKind::Icon => is_nerd_font(&family.name()),
+        })
+        .map(|family| family.name().to_string())
+        .collect();
+    families.sort_by_key(|name| name.to_lowercase());
+    families.dedup();
+    families
+}
+
+fn is_nerd_font(name: &str) -> bool {
+    name.contains("Nerd Font") || name.ends_with(" NF") || name.ends_with(" NFM") || name.ends_with(" NFP")
+}
+
+/// Searchable family dropdown ("System Default" first) with an optional point-size spin button.
+/// `on_family` gets None for the system default; `on_size` is only wired when `size` is Some.
+pub fn create_font_picker<F, S>(kind: FontKind, current: Option<&str>, size: Option<u8>, on_family: F, on_size: S) -> GtkBox
+where
+    F: Fn(Option<String>) + 'static,
+    S: Fn(u8) + 'static,
+{
+    let box_ = GtkBox::new(Orientation::Horizontal, 6);
+    box_.set_valign(gtk4::Align::Center);
+
+    let mut families = installed_families(kind);
+    // Keep a configured family listed even if it's not installed (anymore)
+    if let Some(current) = current {
+        if !families.iter().any(|f| f == current) {
+            families.insert(0, current.to_string());
+        }
+    }
+
+    let model = StringList::new(&[DEFAULT_LABEL]);
+    for family in &families {
+        model.append(family);
+    }
+
+    let dropdown = DropDown::new(Some(model), gtk4::Expression::NONE);
+    dropdown.set_expression(Some(gtk4::PropertyExpression::new(
+        StringObject::static_type(),
+        gtk4::Expression::NONE,
+        "string",
+    )));
+    dropdown.set_enable_search(true);
+    dropdown.set_size_request(220, -1);
+    let selected = current
+        .and_then(|c| families.iter().position(|f| f == c))
+        .map(|i| i as u32 + 1)
+        .unwrap_or(0);
+    dropdown.set_selected(selected);
+
+    dropdown.connect_selected_notify(move |d| {
+        let family = d
+            .selected_item()
+            .and_downcast::<StringObject>()
+            .map(|s| s.string().to_string())
+            .filter(|s| s != DEFAULT_LABEL);
+        on_family(family);
+    });
+    box_.append(&dropdown);
+
+    if let Some(size) = size {
+        let spin = SpinButton::with_range(6.0, 48.0, 1.0);
+        spin.set_value(size as f64);
+        spin.set_tooltip_text(Some("Size (pt)"));
+        spin.connect_value_changed(move |s| on_size(s.value() as u8));
+        box_.append(&spin);
+    }
+
+    box_
+}
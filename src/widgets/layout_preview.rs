@@ -0,0 +1,238 @@
+use gtk4::prelude::*;
+use gtk4::{cairo, Box as GtkBox, DrawingArea, Label, Orientation};
+use std::cell::RefCell;
+
+use crate::core::color;
+use crate::core::config::ColorConfig;
+
+// Schematic desktop size; the drawing scales to whatever the area gets
+const PREVIEW_WIDTH: i32 = 320;
+const PREVIEW_HEIGHT: i32 = 180;
+
+thread_local! {
+    // Unsaved state shown by every preview, and the previews currently alive
+    static PREVIEW_CONFIG: RefCell<Option<ColorConfig>> = const { RefCell::new(None) };
+    static PREVIEWS: RefCell<Vec<gtk4::glib::WeakRef<DrawingArea>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Card with a schematic drawing of the Quickshell layout (bar, dashboard, notifications, colors,
+/// panel opacity and blur).
+/// All previews share one state - see preview_update().
+pub fn create_layout_preview(config: &ColorConfig) -> GtkBox {
+    PREVIEW_CONFIG.with(|state| {
+        state.borrow_mut().get_or_insert_with(|| config.clone());
+    });
+
+    let container = GtkBox::new(Orientation::Vertical, 8);
+    container.add_css_class("card");
+    container.set_margin_bottom(24);
+
+    let area = DrawingArea::new();
+    area.set_content_width(PREVIEW_WIDTH);
+    area.set_content_height(PREVIEW_HEIGHT);
+    area.set_halign(gtk4::Align::Center);
+    area.set_margin_top(16);
+    area.set_draw_func(|_, cr, width, height| {
+        PREVIEW_CONFIG.with(|state| {
+            if let Some(config) = state.borrow().as_ref() {
+                draw_layout(cr, width as f64, height as f64, config);
+            }
+        });
+    });
+    container.append(&area);
+
+    let caption = Label::new(Some("Preview"));
+    caption.add_css_class("row-description");
+    caption.set_margin_bottom(12);
+    container.append(&caption);
+
+    PREVIEWS.with(|previews| {
+        let mut previews = previews.borrow_mut();
+        previews.retain(|weak| weak.upgrade().is_some());
+        previews.push(area.downgrade());
+    });
+
+    container
+}
+
+/// Change the previewed (not yet saved) config and redraw every preview immediately.
+pub fn preview_update<F: FnOnce(&mut ColorConfig)>(f: F) {
+    PREVIEW_CONFIG.with(|state| {
+        let mut state = state.borrow_mut();
+        let config = state.get_or_insert_with(ColorConfig::load);
+        f(config);
+    });
+    redraw_all();
+}
+
+/// Replace the previewed config, e.g. after colors.json changed on disk.
+pub fn preview_set(config: &ColorConfig) {
+    PREVIEW_CONFIG.with(|state| *state.borrow_mut() = Some(config.clone()));
+    redraw_all();
+}
+
+fn redraw_all() {
+    PREVIEWS.with(|previews| {
+        for area in previews.borrow().iter().filter_map(|weak| weak.upgrade()) {
+            area.queue_draw();
+        }
+    });
+}
+
+fn set_color(cr: &cairo::Context, hex: &str, alpha: f64) {
+    let (r, g, b) = color::parse_hex(hex).unwrap_or((128, 128, 128));
+    // Honor #rrggbbaa colors on top of the requested alpha
+    cr.set_source_rgba(r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0, alpha * color::alpha_of(hex));
+}
+
+/// Fill a translucent panel. With blur on, a frosted layer dims whatever shows through.
+fn fill_panel(cr: &cairo::Context, rect: (f64, f64, f64, f64), radius: f64, hex: &str, opacity: u8, config: &ColorConfig) {
+    let alpha = opacity.min(100) as f64 / 100.0;
+    if config.blur_enabled.unwrap_or(false) && alpha < 1.0 {
+        fill_rect(cr, rect, radius, &config.background, (1.0 - alpha) * 0.7);
+    }
+    fill_rect(cr, rect, radius, hex, alpha);
+}
+
+fn rounded_rect(cr: &cairo::Context, x: f64, y: f64, w: f64, h: f64, radius: f64) {
+    let r = radius.min(w / 2.0).min(h / 2.0).max(0.0);
+    let pi = std::f64::consts::PI;
+    cr.new_sub_path();
+    cr.arc(x + w - r, y + r, r, -pi / 2.0, 0.0);
+    cr.arc(x + w - r, y + h - r, r, 0.0, pi / 2.0);
+    cr.arc(x + r, y + h - r, r, pi / 2.0, pi);
+    cr.arc(x + r, y + r, r, pi, 3.0 * pi / 2.0);
+    cr.close_path();
+}
+
+fn fill_rect(cr: &cairo::Context, rect: (f64, f64, f64, f64), radius: f64, hex: &str, alpha: f64) {
+    rounded_rect(cr, rect.0, rect.1, rect.2, rect.3, radius);
+    set_color(cr, hex, alpha);
+    let _ = cr.fill();
+}
+
+fn draw_layout(cr: &cairo::Context, width: f64, height: f64, config: &ColorConfig) {
+    // Corner radius scaled down to the preview (a real screen is ~6x larger)
+    let radius = config.corner_radius() as f64 / 3.0;
+    let bar = (width.min(height) * 0.08).max(8.0);
+    let gap = 4.0;
+
+    // Desktop, with a few diagonal stripes standing in for the wallpaper so transparency shows
+    fill_rect(cr, (0.0, 0.0, width, height), radius * 1.5, &config.background, 1.0);
+    cr.save().ok();
+    rounded_rect(cr, 0.0, 0.0, width, height, radius * 1.5);
+    cr.clip();
+    set_color(cr, &config.accent, 0.35);
+    cr.set_line_width(height * 0.08);
+    let mut offset = -height;
+    while offset < width {
+        cr.move_to(offset, height);
+        cr.line_to(offset + height, 0.0);
+        offset += height * 0.35;
+    }
+    let _ = cr.stroke();
+    cr.restore().ok();
+
+    // Bar / sidebar
+    let position = config.sidebar_position.as_deref().unwrap_or("left");
+    let visible = config.sidebar_visible.unwrap_or(true);
+    let bar_rect = match position {
+        "right" => (width - bar, 0.0, bar, height),
+        "top" => (0.0, 0.0, width, bar),
+        "bottom" => (0.0, height - bar, width, bar),
+        _ => (0.0, 0.0, bar, height),
+    };
+    if visible {
+        fill_panel(cr, bar_rect, 0.0, &config.component_color("sidebar", "primary"), config.surface_opacity("sidebar"), config);
+        draw_workspaces(cr, bar_rect, config);
+    }
+
+    // Free area for windows and popups
+    let (mut x, mut y, mut w, mut h) = (gap, gap, width - 2.0 * gap, height - 2.0 * gap);
+    if visible {
+        match position {
+            "right" => w -= bar,
+            "top" => {
+                y += bar;
+                h -= bar;
+            }
+            "bottom" => h -= bar,
+            _ => {
+                x += bar;
+                w -= bar;
+            }
+        }
+    }
+
+    // A window in the middle
+    let window = (x + w * 0.18, y + h * 0.18, w * 0.64, h * 0.64);
+    fill_rect(cr, window, radius, &config.secondary, 1.0);
+    fill_rect(cr, (window.0, window.1, window.2, 8.0), radius.min(4.0), &config.primary, 1.0);
+    fill_rect(cr, (window.0 + 10.0, window.1 + 18.0, window.2 * 0.5, 4.0), 2.0, &config.text, 0.6);
+    fill_rect(cr, (window.0 + 10.0, window.1 + 28.0, window.2 * 0.35, 4.0), 2.0, &config.text, 0.4);
+
+    // Dashboard
+    let floating = config.floating_dashboard.unwrap_or(true);
+    let inset = if floating { gap } else { 0.0 };
+    let (dw, dh) = (w * 0.32, h * 0.5);
+    let dash_rect = match config.dashboard_position.as_deref().unwrap_or("right") {
+        "left" => (x + inset - gap, y + (h - dh) / 2.0, dw, dh),
+        "top" => (x + (w - dh * 1.6) / 2.0, y + inset - gap, dh * 1.6, dw * 0.8),
+        "bottom" => (x + (w - dh * 1.6) / 2.0, y + h - dw * 0.8 - inset + gap, dh * 1.6, dw * 0.8),
+        _ => (x + w - dw - inset + gap, y + (h - dh) / 2.0, dw, dh),
+    };
+    fill_panel(cr, dash_rect, radius, &config.component_color("dashboard", "primary"), config.surface_opacity("dashboard"), config);
+    rounded_rect(cr, dash_rect.0, dash_rect.1, dash_rect.2, dash_rect.3, radius);
+    set_color(cr, &config.component_color("dashboard", "accent"), 0.8);
+    cr.set_line_width(1.0);
+    let _ = cr.stroke();
+
+    // Notification toast
+    let (nw, nh) = (w * 0.3, 14.0);
+    let nx = match config.notification_position.as_deref().unwrap_or("top") {
+        "top-left" => x + gap,
+        "top-right" => x + w - nw - gap,
+        _ => x + (w - nw) / 2.0,
+    };
+    let toast = (nx, y + gap, nw, nh);
+    fill_panel(cr, toast, radius, &config.component_color("notifications", "secondary"), config.surface_opacity("notifications"), config);
+    fill_rect(cr, (toast.0 + 3.0, toast.1 + 3.0, 3.0, nh - 6.0), 1.5, &config.component_color("notifications", "accent"), 1.0);
+    fill_rect(cr, (toast.0 + 10.0, toast.1 + 5.0, nw * 0.55, 4.0), 2.0, &config.component_color("notifications", "text"), 0.6);
+}
+
+/// Workspace indicators along the bar, as dots or lines, at the configured end of the bar.
+fn draw_workspaces(cr: &cairo::Context, bar: (f64, f64, f64, f64), config: &ColorConfig) {
+    let vertical = bar.3 > bar.2;
+    let thickness = bar.2.min(bar.3);
+    let length = bar.2.max(bar.3);
+    let count = 5;
+    let spacing = thickness * 0.8;
+    let total = spacing * count as f64;
+    let start = match config.sidebar_workspace_mode.as_deref().unwrap_or("top") {
+        "center" => (length - total) / 2.0,
+        "bottom" => length - total - thickness * 0.5,
+        _ => thickness * 0.5,
+    };
+    let dots = config.sidebar_style.as_deref().unwrap_or("dots") == "dots";
+
+    for i in 0..count {
+        let along = start + spacing * i as f64 + spacing / 2.0;
+        let (cx, cy) = if vertical {
+            (bar.0 + thickness / 2.0, bar.1 + along)
+        } else {
+            (bar.0 + along, bar.1 + thickness / 2.0)
+        };
+        let hex = if i == 0 { config.component_color("sidebar", "accent") } else { config.component_color("sidebar", "text") };
+        let alpha = if i == 0 { 1.0 } else { 0.5 };
+        let size = thickness * 0.3;
+        if dots {
+            cr.arc(cx, cy, size / 2.0, 0.0, 2.0 * std::f64::consts::PI);
+            set_color(cr, &hex, alpha);
+            let _ = cr.fill();
+        } else if vertical {
+            fill_rect(cr, (cx - size, cy - 1.0, size * 2.0, 2.0), 1.0, &hex, alpha);
+        } else {
+            fill_rect(cr, (cx - 1.0, cy - size, 2.0, size * 2.0), 1.0, &hex, alpha);
+        }
+    }
+}
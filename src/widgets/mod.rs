@@ -1,2 +1,5 @@
 pub mod color_picker;
+pub mod duplicates_dialog;
+pub mod font_picker;
+pub mod layout_preview;
 pub mod wallpaper_tile;
\ No newline at end of file
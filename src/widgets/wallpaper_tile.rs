@@ -1,2 +1,135 @@
-// Placeholder for wallpaper tile widget
-// Currently implemented inline in wallpapers.rs
+use gtk4::prelude::*;
+use gtk4::{gio, glib, Label, Picture, Widget};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+use crate::core::thumbnails;
+use crate::core::wallpapers::{self, WallpaperKind};
+
+// Decoding a 4K image takes a lot of memory, so only a couple run at once
+const MAX_WORKERS: usize = 2;
+
+struct ThumbnailJob {
+    path: PathBuf,
+    picture: glib::WeakRef<Picture>,
+    placeholder: glib::WeakRef<Widget>,
+}
+
+thread_local! {
+    static QUEUE: RefCell<VecDeque<ThumbnailJob>> = const { RefCell::new(VecDeque::new()) };
+    static WORKERS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Show the thumbnail of `path` in `picture`, generating it in the background if it isn't cached.
+/// `placeholder` stays visible until then.
+pub fn load_thumbnail(picture: &Picture, placeholder: &impl IsA<Widget>, path: &Path) {
+    if let Some(thumb) = thumbnails::cached_thumbnail(path) {
+        picture.set_filename(Some(&thumb));
+        placeholder.set_visible(false);
+        return;
+    }
+
+    QUEUE.with(|queue| {
+        queue.borrow_mut().push_back(ThumbnailJob {
+            path: path.to_path_buf(),
+            picture: picture.downgrade(),
+            placeholder: placeholder.upcast_ref::<Widget>().downgrade(),
+        })
+    });
+
+    if WORKERS.get() < MAX_WORKERS {
+        WORKERS.set(WORKERS.get() + 1);
+        glib::MainContext::default().spawn_local(run_worker());
+    }
+}
+
+async fn run_worker() {
+    while let Some(job) = QUEUE.with(|queue| queue.borrow_mut().pop_front()) {
+        // Tile already gone (tab rebuilt) - nothing to show it in
+        if job.picture.upgrade().is_none() {
+            continue;
+        }
+
+        let path = job.path.clone();
+        let result = gio::spawn_blocking(move || thumbnails::generate_thumbnail(&path).ok()).await;
+
+        if let Some(picture) = job.picture.upgrade() {
+            match result {
+                Ok(Some(thumb)) => picture.set_filename(Some(&thumb)),
+                // Formats the image crate can't decode may still load in GTK
+                _ => picture.set_filename(Some(&job.path)),
+            }
+        }
+        if let Some(placeholder) = job.placeholder.upgrade() {
+            placeholder.set_visible(false);
+        }
+    }
+    WORKERS.set(WORKERS.get() - 1);
+}
+
+/// Label `badge` with the kind of `path` ("ANIM", "VIDEO") and play animated wallpapers and
+/// videos in `picture` on hover. Both stay off for still images; GIF and WebP files are checked
+/// for animation in the background.
+pub fn show_kind(picture: &Picture, badge: &Label, path: &Path) {
+    badge.set_visible(false);
+    let ext = path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default();
+    if !wallpapers::is_video_file(path) && ext != "gif" && ext != "webp" {
+        return;
+    }
+
+    let picture = picture.downgrade();
+    let badge = badge.downgrade();
+    let path = path.to_path_buf();
+    glib::MainContext::default().spawn_local(async move {
+        let path_clone = path.clone();
+        let kind = gio::spawn_blocking(move || WallpaperKind::of(&path_clone)).await.unwrap_or(WallpaperKind::Image);
+        let Some(text) = kind.badge() else {
+            return;
+        };
+        if let Some(badge) = badge.upgrade() {
+            badge.set_text(text);
+            badge.set_visible(true);
+        }
+        if let Some(picture) = picture.upgrade() {
+            play_on_hover(&picture, &path);
+        }
+    });
+}
+
+/// Play animated wallpapers and videos in `picture` while the pointer is over it,
+/// and go back to the first-frame thumbnail afterwards.
+fn play_on_hover(picture: &Picture, path: &Path) {
+    let motion = gtk4::EventControllerMotion::new();
+    {
+        let picture = picture.downgrade();
+        let path = path.to_path_buf();
+        motion.connect_enter(move |_, _, _| {
+            let Some(picture) = picture.upgrade() else {
+                return;
+            };
+            let media = gtk4::MediaFile::for_filename(&path);
+            media.set_loop(true);
+            media.set_muted(true);
+            media.play();
+            picture.set_paintable(Some(&media));
+        });
+    }
+    {
+        let picture = picture.downgrade();
+        let path = path.to_path_buf();
+        motion.connect_leave(move |_| {
+            let Some(picture) = picture.upgrade() else {
+                return;
+            };
+            if let Some(media) = picture.paintable().and_downcast::<gtk4::MediaStream>() {
+                media.pause();
+            }
+            match thumbnails::cached_thumbnail(&path) {
+                Some(thumb) => picture.set_filename(Some(&thumb)),
+                None => picture.set_paintable(gtk4::gdk::Paintable::NONE),
+            }
+        });
+    }
+    picture.add_controller(motion);
+}
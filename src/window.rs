@@ -87,8 +87,13 @@ impl FuseWindow {
         stack.set_margin_bottom(12);
         main_box.append(&stack);
 
+        // Parse errors in the user's fuse.css show up above everything else
+        let root_box = GtkBox::new(Orientation::Vertical, 0);
+        root_box.append(&crate::app::create_user_css_banner());
+        root_box.append(&main_box);
+
         window.set_title(Some("⚙️ Fuse Settings"));
-        window.set_content(Some(&main_box));
+        window.set_content(Some(&root_box));
 
         let lazy_built = Rc::new(RefCell::new(HashSet::new()));
         let lazy_placeholders = Rc::new(RefCell::new((